@@ -0,0 +1,119 @@
+use crate::*;
+
+/// Builds a [`Game`] that's already in progress
+///
+/// Anything not set is as it would be at the start of a new game, so only
+/// the parts of the position that matter need setting, e.g.:
+///
+/// ```
+/// # use game::*;
+/// # use game::Card::*;
+/// let game: Game<3> = GameBuilder::new()
+///     .cards_played([fvec![Flower], fvec![Skull], fvec![Flower]])
+///     .state(State::Playing { current_player: 1 })
+///     .build()
+///     .unwrap();
+/// ```
+///
+/// The position is checked against the same invariants as
+/// [`Game::validate_state()`] when it's built
+#[derive(Debug, Clone)]
+pub struct GameBuilder<const N: usize> {
+    pub(crate) config: GameConfig,
+    pub(crate) scores: [u8; N],
+    pub(crate) player_hands: Option<[Hand; N]>,
+    pub(crate) cards_played: [OrderedHand; N],
+    pub(crate) state: State<N>,
+    pub(crate) pending_event: Option<Event>,
+    pub(crate) round: usize,
+    pub(crate) seed: Option<u64>,
+}
+
+impl<const N: usize> GameBuilder<N> {
+    const CARDS_PLAYED_INIT: OrderedHand = fvec![];
+
+    /// Creates a builder for the position at the start of a new game
+    pub fn new() -> Self {
+        GameBuilder {
+            config: GameConfig::default(),
+            scores: [0; N],
+            player_hands: None,
+            cards_played: [Self::CARDS_PLAYED_INIT; N],
+            state: State::Playing { current_player: 0 },
+            pending_event: None,
+            round: 0,
+            seed: None,
+        }
+    }
+
+    /// Sets the rules the game is played with
+    ///
+    /// Unless [`hands()`](GameBuilder::hands()) is used, every player's hand
+    /// will be the rules' starting hand
+    pub fn config(mut self, config: GameConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets each player's score
+    pub fn scores(mut self, scores: [u8; N]) -> Self {
+        self.scores = scores;
+        self
+    }
+
+    /// Sets the cards each player has left, whether played or not
+    pub fn hands(mut self, player_hands: [Hand; N]) -> Self {
+        self.player_hands = Some(player_hands);
+        self
+    }
+
+    /// Sets the cards each player has played this round, ordered bottom to
+    /// top
+    pub fn cards_played(mut self, cards_played: [OrderedHand; N]) -> Self {
+        self.cards_played = cards_played;
+        self
+    }
+
+    /// Sets the state of the game
+    pub fn state(mut self, state: State<N>) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Sets the event that will be returned by the next call to
+    /// [`Game::what_next()`]
+    pub fn pending_event(mut self, event: Event) -> Self {
+        self.pending_event = Some(event);
+        self
+    }
+
+    /// Sets the round number
+    pub fn round(mut self, round: usize) -> Self {
+        self.round = round;
+        self
+    }
+
+    /// Sets the seed for the game's random number generator
+    ///
+    /// If not set, it's seeded from the system's source of entropy
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Builds the game, if the position is valid
+    ///
+    /// Every invariant the position breaks is returned otherwise.
+    /// The game starts with an empty history, so can't be replayed (see
+    /// [`Game::is_replayable()`])
+    pub fn build(self) -> Result<Game<N>, Vec<InvariantViolation>> {
+        let game = Game::from_builder(self);
+        game.validate_state().map(|_| game)
+    }
+}
+
+impl<const N: usize> Default for GameBuilder<N> {
+    fn default() -> Self {
+        GameBuilder::new()
+    }
+}
@@ -0,0 +1,100 @@
+use crate::*;
+
+/// The rules a [`Game`] is played with
+///
+/// The default is the rules of the original game.
+/// Change any of the fields to play with house rules, e.g.:
+///
+/// ```
+/// # use game::*;
+/// let config = GameConfig {
+///     points_to_win: 3,
+///     ..GameConfig::default()
+/// };
+/// let game: Game<4> = Game::with_config(config);
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameConfig {
+    /// The number of successful challenges needed to win the game
+    ///
+    /// Must be at least 1.
+    /// Defaults to 2
+    pub points_to_win: u8,
+    /// The hand every player starts the game with
+    ///
+    /// Must have at least one card in it.
+    /// Defaults to a full hand (see [`Hand::new()`])
+    pub starting_hand: Hand,
+    /// Whether the challenger's own cards are flipped automatically at the
+    /// start of a challenge, before they flip anyone else's
+    ///
+    /// If `false`, the challenger flips any cards they like, including their
+    /// own, in any order.
+    /// Defaults to `true`
+    pub challenger_flips_own_first: bool,
+    /// How the card a challenger loses is chosen
+    ///
+    /// Defaults to [`DiscardMode::Chosen`]
+    pub discard_mode: DiscardMode,
+    /// Who plays the first card of the game
+    ///
+    /// Must be a seat in the game if it's [`FirstPlayer::Seat`].
+    /// Defaults to seat 0
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub first_player: FirstPlayer,
+}
+
+impl GameConfig {
+    /// Returns `true` if a player could hold `hand` when playing by these
+    /// rules, i.e. it has no more of any card than the starting hand
+    pub fn allows(&self, hand: Hand) -> bool {
+        self.starting_hand.is_superset_of(hand)
+    }
+
+    /// Makes a hand from the given cards, like `Hand::try_from()`, but also
+    /// checking the rules [allow](GameConfig::allows()) it
+    pub fn hand_from(&self, cards: &[Card]) -> Result<Hand, HandError> {
+        let hand = Hand::try_from(cards)?;
+        if self.allows(hand) {
+            Ok(hand)
+        } else {
+            Err(HandError::NotAllowed(hand))
+        }
+    }
+
+    /// Checks the rules can be played with by the given number of players
+    ///
+    /// [`Game::with_config()`] panics with the reason given here, so use
+    /// this first for rules from elsewhere, e.g. user input
+    pub fn check_valid(&self, player_count: usize) -> Result<(), &'static str> {
+        if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&player_count) {
+            Err("Invalid number of players")
+        } else if self.points_to_win == 0 {
+            Err("Points to win must be at least 1")
+        } else if self.starting_hand.empty() {
+            Err("Starting hand must have at least one card")
+        } else if !self.starting_hand.is_valid() {
+            Err("Starting hand has too many cards")
+        } else if matches!(
+            self.first_player,
+            FirstPlayer::Seat(seat) if seat >= player_count
+        ) {
+            Err("First player's seat is out of range")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            points_to_win: 2,
+            starting_hand: Hand::new(),
+            challenger_flips_own_first: true,
+            discard_mode: DiscardMode::default(),
+            first_player: FirstPlayer::default(),
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use crate::*;
+
+use std::any::Any;
+
+// Calls the same code on whichever Game<N> is inside a DynGame
+macro_rules! with_game {
+    ($dyn_game:expr, $game:ident => $body:expr) => {
+        match $dyn_game {
+            DynGame::Two($game) => $body,
+            DynGame::Three($game) => $body,
+            DynGame::Four($game) => $body,
+            DynGame::Five($game) => $body,
+            DynGame::Six($game) => $body,
+            DynGame::Seven($game) => $body,
+            DynGame::Eight($game) => $body,
+            DynGame::Nine($game) => $body,
+            DynGame::Ten($game) => $body,
+        }
+    };
+}
+
+/// A [`Game`] with a number of players chosen at runtime
+///
+/// `DynGame` is an enum over every supported player count, so it is just as
+/// heapless as `Game` (and the same size as the largest `Game`).
+/// It provides all of the methods of `Game` that don't depend on the number
+/// of players.
+/// For the rest (e.g. [`Game::state()`]), use
+/// [`as_game()`](DynGame::as_game()) or match on the variants
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DynGame {
+    /// A game with 2 players
+    Two(Game<2>),
+    /// A game with 3 players
+    Three(Game<3>),
+    /// A game with 4 players
+    Four(Game<4>),
+    /// A game with 5 players
+    Five(Game<5>),
+    /// A game with 6 players
+    Six(Game<6>),
+    /// A game with 7 players
+    Seven(Game<7>),
+    /// A game with 8 players
+    Eight(Game<8>),
+    /// A game with 9 players
+    Nine(Game<9>),
+    /// A game with 10 players
+    Ten(Game<10>),
+}
+
+impl DynGame {
+    /// Creates a new game for the given number of players
+    ///
+    /// Returns `None` if the number of players isn't supported
+    ///
+    /// See [`Game::new()`]
+    pub fn new(player_count: usize) -> Option<Self> {
+        Self::with_seed(player_count, GameRng::entropy_seed())
+    }
+
+    /// Creates a new game for the given number of players, with its random
+    /// number generator seeded by `seed`
+    ///
+    /// Returns `None` if the number of players isn't supported
+    ///
+    /// See [`Game::with_seed()`]
+    pub fn with_seed(player_count: usize, seed: u64) -> Option<Self> {
+        Some(match player_count {
+            2 => DynGame::Two(Game::with_seed(seed)),
+            3 => DynGame::Three(Game::with_seed(seed)),
+            4 => DynGame::Four(Game::with_seed(seed)),
+            5 => DynGame::Five(Game::with_seed(seed)),
+            6 => DynGame::Six(Game::with_seed(seed)),
+            7 => DynGame::Seven(Game::with_seed(seed)),
+            8 => DynGame::Eight(Game::with_seed(seed)),
+            9 => DynGame::Nine(Game::with_seed(seed)),
+            10 => DynGame::Ten(Game::with_seed(seed)),
+            _ => return None,
+        })
+    }
+
+    /// Creates a new game for the given number of players, played with the
+    /// given rules
+    ///
+    /// Returns `None` if the number of players isn't supported
+    ///
+    /// See [`Game::with_config()`]
+    pub fn with_config(
+        player_count: usize,
+        config: GameConfig,
+    ) -> Option<Self> {
+        Some(match player_count {
+            2 => DynGame::Two(Game::with_config(config)),
+            3 => DynGame::Three(Game::with_config(config)),
+            4 => DynGame::Four(Game::with_config(config)),
+            5 => DynGame::Five(Game::with_config(config)),
+            6 => DynGame::Six(Game::with_config(config)),
+            7 => DynGame::Seven(Game::with_config(config)),
+            8 => DynGame::Eight(Game::with_config(config)),
+            9 => DynGame::Nine(Game::with_config(config)),
+            10 => DynGame::Ten(Game::with_config(config)),
+            _ => return None,
+        })
+    }
+
+    /// Gets the inner game, if it has `N` players
+    pub fn as_game<const N: usize>(&self) -> Option<&Game<N>> {
+        with_game!(self, game => (game as &dyn Any).downcast_ref())
+    }
+
+    /// Gets the inner game mutably, if it has `N` players
+    pub fn as_game_mut<const N: usize>(&mut self) -> Option<&mut Game<N>> {
+        with_game!(self, game => (game as &mut dyn Any).downcast_mut())
+    }
+
+    /// See [`Game::player_count()`]
+    pub const fn player_count(&self) -> usize {
+        with_game!(self, game => game.player_count())
+    }
+
+    /// See [`Game::what_next()`]
+    pub fn what_next(&mut self) -> Result<Event, EngineError> {
+        with_game!(self, game => game.what_next())
+    }
+
+    /// See [`Game::respond()`]
+    pub fn respond(&mut self, response: Response) -> Result<(), ResponseError> {
+        with_game!(self, game => game.respond(response))
+    }
+
+    /// See [`Game::validate()`]
+    pub fn validate(&self, response: Response) -> Result<(), ResponseError> {
+        with_game!(self, game => game.validate(response))
+    }
+
+    /// See [`Game::legal_responses()`]
+    pub fn legal_responses(&self) -> Vec<Response> {
+        with_game!(self, game => game.legal_responses())
+    }
+
+    /// See [`Game::scores()`]
+    pub const fn scores(&self) -> &[u8] {
+        with_game!(self, game => game.scores())
+    }
+
+    /// See [`Game::hands()`]
+    pub const fn hands(&self) -> &[Hand] {
+        with_game!(self, game => game.hands())
+    }
+
+    /// See [`Game::cards_played()`]
+    pub fn cards_played(&self) -> Vec<&[Card]> {
+        with_game!(self, game => game.cards_played())
+    }
+
+    /// See [`Game::round()`]
+    pub const fn round(&self) -> usize {
+        with_game!(self, game => game.round())
+    }
+
+    /// See [`Game::history()`]
+    pub fn history(&self) -> &[HistoryEntry] {
+        with_game!(self, game => game.history())
+    }
+
+    /// See [`Game::winner()`]
+    pub const fn winner(&self) -> Option<usize> {
+        with_game!(self, game => game.winner())
+    }
+
+    /// See [`Game::remaining_player_count()`]
+    pub fn remaining_player_count(&self) -> usize {
+        with_game!(self, game => game.remaining_player_count())
+    }
+
+    /// See [`Game::seed()`]
+    pub const fn seed(&self) -> u64 {
+        with_game!(self, game => game.seed())
+    }
+
+    /// See [`Game::reseed()`]
+    pub fn reseed(&mut self, seed: u64) {
+        with_game!(self, game => game.reseed(seed))
+    }
+
+    /// See [`Game::config()`]
+    pub const fn config(&self) -> &GameConfig {
+        with_game!(self, game => game.config())
+    }
+
+    /// See [`Game::discard_mode()`]
+    pub const fn discard_mode(&self) -> DiscardMode {
+        with_game!(self, game => game.discard_mode())
+    }
+
+    /// See [`Game::set_discard_mode()`]
+    pub fn set_discard_mode(&mut self, discard_mode: DiscardMode) -> bool {
+        with_game!(self, game => game.set_discard_mode(discard_mode))
+    }
+
+    /// See [`Game::is_replayable()`]
+    pub const fn is_replayable(&self) -> bool {
+        with_game!(self, game => game.is_replayable())
+    }
+
+    /// See [`Game::undo_depth()`]
+    pub const fn undo_depth(&self) -> usize {
+        with_game!(self, game => game.undo_depth())
+    }
+
+    /// See [`Game::set_undo_depth()`]
+    pub fn set_undo_depth(&mut self, depth: usize) {
+        with_game!(self, game => game.set_undo_depth(depth))
+    }
+
+    /// See [`Game::undo_count()`]
+    pub fn undo_count(&self) -> usize {
+        with_game!(self, game => game.undo_count())
+    }
+
+    /// See [`Game::undo()`]
+    pub fn undo(&mut self) -> Option<Response> {
+        with_game!(self, game => game.undo())
+    }
+}
+
+macro_rules! impl_from_game {
+    ($($n:literal => $variant:ident),*) => {
+        $(
+            impl From<Game<$n>> for DynGame {
+                fn from(game: Game<$n>) -> Self {
+                    DynGame::$variant(game)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_game!(
+    2 => Two,
+    3 => Three,
+    4 => Four,
+    5 => Five,
+    6 => Six,
+    7 => Seven,
+    8 => Eight,
+    9 => Nine,
+    10 => Ten
+);
@@ -1,5 +1,16 @@
 use crate::*;
 
+use std::collections::VecDeque;
+
+// Returns the given error message if the condition isn't met
+macro_rules! ensure {
+    ($cond:expr, $msg:expr) => {
+        if !$cond {
+            return Err($msg);
+        }
+    };
+}
+
 /// A simulation of a game of Skull
 ///
 /// Keeps track of scores, players' cards, the state of the game, everything
@@ -7,36 +18,224 @@ use crate::*;
 ///
 /// Game is (mostly) heapless and so is (somewhat unfortunately) generic over
 /// the number of players.
-/// This can be 3 to 6, inclusive
+/// This can be [`MIN_PLAYERS`] to [`MAX_PLAYERS`], inclusive.
+/// If the number of players is only known at runtime, use [`DynGame`]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Game<const N: usize> {
-    scores: [u8; N],                // public via getter
-    player_hands: [Hand; N],        // public via getter
-    cards_played: [OrderedHand; N], // FVec<[Card; 4]> is ordered bottom -> top
-    state: State<N>,                // public via getter
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    scores: [u8; N], // public via getter
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    player_hands: [Hand; N], // public via getter
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    cards_played: [OrderedHand; N], // ordered bottom -> top
+    state: State<N>, // public via getter
     pending_event: Option<Event>,
+    round: usize,               // public via getter
+    history: Vec<HistoryEntry>, // public via getter
+    seed: u64,                  // public via getter
+    rng: GameRng,
+    config: GameConfig, // public via getter
+    replayable: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    undo_depth: usize, // public via getter
+    #[cfg_attr(feature = "serde", serde(skip))]
+    undo_stack: VecDeque<Snapshot<N>>, // oldest -> newest
+}
+
+// Everything needed to take a Game back to before a response
+#[derive(Debug, Clone)]
+struct Snapshot<const N: usize> {
+    scores: [u8; N],
+    player_hands: [Hand; N],
+    cards_played: [OrderedHand; N],
+    state: State<N>,
+    pending_event: Option<Event>,
+    round: usize,
+    history_len: usize,
+    rng: GameRng,
 }
 
 impl<const N: usize> Game<N> {
     const CARDS_PLAYED_INIT: OrderedHand = fvec![];
-    const STATE_FLIPPED_INIT: FVec<usize, 4> = fvec![];
+    const STATE_FLIPPED_INIT: FVec<usize, MAX_HAND_SIZE> = fvec![];
 
     /// Creates a new game
     ///
     /// The first action the `Game` will expect is [`Response::PlayCard`],
     /// to be provided using [`respond()`](Game::respond())
+    ///
+    /// The game's random number generator is seeded from the system's source
+    /// of entropy.
+    /// Use [`Game::with_seed()`] for a reproducible game
     pub fn new() -> Self {
-        assert!((3..=6).contains(&N), "Invalid number of players");
+        Game::with_seed(GameRng::entropy_seed())
+    }
+
+    /// Creates a new game played with the given rules
+    ///
+    /// Like [`Game::new()`], the game's random number generator is seeded
+    /// from the system's source of entropy.
+    /// Use [`reseed()`](Game::reseed()) straight after creating the game for
+    /// a reproducible game
+    ///
+    /// Panics if the rules can't be played with (see
+    /// [`GameConfig::check_valid()`])
+    pub fn with_config(config: GameConfig) -> Self {
+        Game::create(config, GameRng::entropy_seed())
+    }
+
+    /// Creates a new game with its random number generator seeded by `seed`
+    ///
+    /// Two games created with the same seed and given the same responses will
+    /// play out identically
+    pub fn with_seed(seed: u64) -> Self {
+        Game::create(GameConfig::default(), seed)
+    }
+
+    fn create(config: GameConfig, seed: u64) -> Self {
+        assert!(
+            (MIN_PLAYERS..=MAX_PLAYERS).contains(&N),
+            "Invalid number of players"
+        );
+        if let Err(reason) = config.check_valid(N) {
+            panic!("Invalid config: {}", reason);
+        }
+        let mut rng = GameRng::new(seed);
+        let current_player = Self::choose_first_player(&config, &mut rng);
 
         Game {
             scores: [0; N],
-            player_hands: [Hand::new(); N],
+            player_hands: [config.starting_hand; N],
             cards_played: [Self::CARDS_PLAYED_INIT; N],
-            state: Playing { current_player: 0 },
+            state: Playing { current_player },
             pending_event: None,
+            round: 0,
+            history: Vec::new(),
+            seed,
+            rng,
+            config,
+            replayable: true,
+            undo_depth: 0,
+            undo_stack: VecDeque::new(),
+        }
+    }
+
+    /// Gets the seed the game's random number generator was created with
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Gets the rules the game is being played with
+    pub const fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    /// Gets how the game decides which card a challenger loses
+    pub const fn discard_mode(&self) -> DiscardMode {
+        self.config.discard_mode
+    }
+
+    /// Sets how the game decides which card a challenger loses
+    ///
+    /// Only takes effect for challenges lost after it is set.
+    /// Returns `false`, leaving the mode unchanged, if a challenger has
+    /// flipped a skull but hasn't lost a card yet
+    pub fn set_discard_mode(&mut self, discard_mode: DiscardMode) -> bool {
+        // The old mode has already been used for the lost challenge
+        if matches!(self.pending_event, Some(ChallengerChoseSkull { .. }))
+            || matches!(self.state, Discarding { .. })
+        {
+            return false;
+        }
+        self.config.discard_mode = discard_mode;
+        // Replays only know the rules the game started with
+        self.replayable &= self.history.is_empty();
+        true
+    }
+
+    /// Reseeds the game's random number generator
+    ///
+    /// Useful for making a game created using
+    /// [`create_from()`](Game::create_from()) reproducible
+    /// (see also [`GameBuilder::seed()`])
+    ///
+    /// If the game hasn't started, and its first player is chosen at random
+    /// (see [`FirstPlayer`]), the first player is chosen again using the new
+    /// seed
+    pub fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = GameRng::new(seed);
+        // Replays only know the seed the game started with
+        self.replayable &= self.history.is_empty();
+        if self.replayable {
+            let current_player =
+                Self::choose_first_player(&self.config, &mut self.rng);
+            self.state = Playing { current_player };
+        }
+    }
+
+    fn choose_first_player(config: &GameConfig, rng: &mut GameRng) -> usize {
+        match config.first_player {
+            FirstPlayer::Seat(seat) => seat,
+            FirstPlayer::Random => rng.generate_range(0..N),
+        }
+    }
+
+    /// Returns `true` if the game can be recreated from its seed, rules and
+    /// the responses in its history
+    ///
+    /// See [`Replay::from_game()`]
+    pub const fn is_replayable(&self) -> bool {
+        self.replayable
+    }
+
+    /// Gets the maximum number of responses that can be undone
+    ///
+    /// See [`undo()`](Game::undo())
+    pub const fn undo_depth(&self) -> usize {
+        self.undo_depth
+    }
+
+    /// Sets the maximum number of responses that can be undone
+    ///
+    /// Defaults to 0, which disables undoing.
+    /// Lowering the depth forgets the oldest responses that could have been
+    /// undone
+    pub fn set_undo_depth(&mut self, depth: usize) {
+        self.undo_depth = depth;
+        while self.undo_stack.len() > depth {
+            self.undo_stack.pop_front();
         }
     }
 
+    /// Gets the number of responses that can currently be undone
+    pub fn undo_count(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    /// Takes the game back to before the last accepted [`Response`],
+    /// returning that response
+    ///
+    /// Any events processed since the response are also undone, and removed
+    /// from the [history](Game::history()).
+    /// Returns `None` if there's nothing to undo
+    ///
+    /// Once a card has been flipped, nothing before it can be undone, as
+    /// players will have seen the card.
+    /// The same goes for a chosen discard
+    pub fn undo(&mut self) -> Option<Response> {
+        let snapshot = self.undo_stack.pop_back()?;
+        let response = match self.history[snapshot.history_len].item {
+            HistoryItem::Response(response) => response,
+            HistoryItem::Event(_) => {
+                unreachable!("Snapshot should be taken before a response")
+            }
+        };
+        self.restore(snapshot);
+        Some(response)
+    }
+
     /// Gets a slice of the game's scores
     ///
     /// Length will be equal to the number of players
@@ -63,6 +262,30 @@ impl<const N: usize> Game<N> {
         &self.state
     }
 
+    /// Gets the number of the current round, starting from 0
+    ///
+    /// A round ends when a challenge ends
+    pub const fn round(&self) -> usize {
+        self.round
+    }
+
+    /// Gets every accepted [`Response`] and emitted notification [`Event`],
+    /// oldest first
+    ///
+    /// The history is append-only and is the only part of the `Game` that
+    /// grows on the heap as play goes on
+    pub fn history(&self) -> &[HistoryEntry] {
+        &self.history
+    }
+
+    /// Gets a [`PlayerView`] of the game, containing only the information
+    /// the given player is allowed to know
+    ///
+    /// Panics if `player_index` is out of range
+    pub fn view_for(&self, player_index: usize) -> PlayerView<N> {
+        PlayerView::new(self, player_index)
+    }
+
     /// Notifies of events or tells you what input is required
     ///
     /// If in doubt, use this to work out what's going on
@@ -71,161 +294,333 @@ impl<const N: usize> Game<N> {
     /// where necessary, may also mutate the [`State`](Game::state()) of the
     /// `Game`
     ///
+    /// Once the game is [finished](State::Finished), this will keep returning
+    /// the event that ended the game
+    ///
+    /// Returns an [`EngineError`] if the game is found to be in an
+    /// inconsistent state, and the game is left unchanged
+    ///
     /// See also: [`Event`]
-    pub fn what_next(&mut self) -> Event {
+    pub fn what_next(&mut self) -> Result<Event, EngineError> {
         use Event::*;
-        use InputType::*;
         // if let uses less indendation than match
         if let Some(event) = self.pending_event {
-            match event {
-                ChallengeStarted => {
-                    if let State::Challenging {
-                        challenger,
-                        target,
-                        flipped,
-                    } = &mut self.state
-                    {
-                        // Pull out self.cards_played or else the compiler
-                        // will get aggro later
-                        let challenger_cards_played =
-                            &self.cards_played[*challenger];
-                        let challenger_cards_played_count =
-                            challenger_cards_played.len();
-                        // Flip own cards
-                        /*
-                        Offset ensures only the correct players cards are
-                        flipped, in the event that only some of the
-                        player's cards need flipping. If target >
-                        challenger_cards_played, then the offset will be
-                        0 and all cards will be flipped.
-                         */
-                        let offset = challenger_cards_played_count
-                            .saturating_sub(*target);
-
-                        flipped[*challenger] = (offset
-                            ..challenger_cards_played_count)
-                            .into_iter()
-                            .collect();
-
-                        /*
-                        Check if any of those flipped cards are a skull
-                        No point in making this a function as we're only
-                        going to be doing this one card at a time in future
-                        */
-                        let flipped_skull =
-                            flipped[*challenger].iter().any(|index| {
-                                /*println!(
-                                    "Card at index {} is a {}",
-                                    index, challenger_cards_played[*index]
-                                );*/
-                                matches!(challenger_cards_played[*index], Skull)
-                            });
-                        if flipped_skull {
-                            self.player_hands[*challenger].discard_one();
-                            self.pending_event = Some(ChallengerChoseSkull {
-                                challenger: *challenger,
-                                skull_player: *challenger,
-                            });
-                        } else if *target <= challenger_cards_played_count {
-                            // If we only need to flip (some of) the
-                            // challenger's cards, and have found no skulls,
-                            // they've won the challenge
-                            self.scores[*challenger] += 1;
-                            self.pending_event =
-                                if self.scores[*challenger] != 2 {
-                                    Some(ChallengeWon(*challenger))
-                                } else {
-                                    Some(ChallengeWonGameWon(*challenger))
-                                };
-                        } else {
-                            // Nothing exciting has happened, challenger needs
-                            // to continue flipping cards
-                            self.pending_event = None;
+            let snapshot = self.snapshot();
+            self.history.push(HistoryEntry {
+                round: self.round,
+                player: self.event_player(event),
+                item: HistoryItem::Event(event),
+            });
+            if let Err(err) = self.process_event(event) {
+                // Handling an event can fail part way through, so put the
+                // game back how it was, without the event recorded
+                self.restore(snapshot);
+                return Err(err);
+            }
+            Ok(event)
+        } else if let Finished { winner, reason } = self.state {
+            // The game is over, keep telling everyone who won
+            Ok(match reason {
+                WinReason::ChallengesWon => ChallengeWonGameWon(winner),
+                WinReason::LastPlayerStanding => LastPlayerStanding(winner),
+            })
+        } else {
+            Ok(Event::Input {
+                player: self.player(),
+                input: self.input_type(),
+            })
+        }
+    }
+
+    // Acts on a pending event that's just been taken by what_next()
+    fn process_event(&mut self, event: Event) -> Result<(), EngineError> {
+        use Event::*;
+        match event {
+            ChallengeStarted => {
+                if !self.config.challenger_flips_own_first {
+                    // Challenger flips all cards themselves
+                    self.pending_event = None;
+                } else if let State::Challenging {
+                    challenger,
+                    target,
+                    flipped,
+                } = &mut self.state
+                {
+                    // Pull out self.cards_played or else the compiler
+                    // will get aggro later
+                    let challenger_cards_played =
+                        &self.cards_played[*challenger];
+                    let challenger_cards_played_count =
+                        challenger_cards_played.len();
+                    // Flip own cards, which can't be undone
+                    self.undo_stack.clear();
+                    /*
+                    Offset ensures only the correct players cards are
+                    flipped, in the event that only some of the
+                    player's cards need flipping. If target >
+                    challenger_cards_played, then the offset will be
+                    0 and all cards will be flipped.
+                     */
+                    let offset =
+                        challenger_cards_played_count.saturating_sub(*target);
+
+                    flipped[*challenger] =
+                        (offset..challenger_cards_played_count).collect();
+
+                    /*
+                    Check if any of those flipped cards are a skull
+                    No point in making this a function as we're only
+                    going to be doing this one card at a time in future
+                    */
+                    let flipped_skull =
+                        flipped[*challenger].iter().any(|index| {
+                            challenger_cards_played[*index].loses_challenge()
+                        });
+                    if flipped_skull {
+                        if self.config.discard_mode == DiscardMode::Random {
+                            self.player_hands[*challenger]
+                                .discard_one(&mut self.rng);
                         }
+                        self.pending_event = Some(ChallengerChoseSkull {
+                            challenger: *challenger,
+                            skull_player: *challenger,
+                        });
+                    } else if *target <= challenger_cards_played_count {
+                        // If we only need to flip (some of) the
+                        // challenger's cards, and have found no skulls,
+                        // they've won the challenge
+                        self.scores[*challenger] += 1;
+                        self.pending_event = if self.scores[*challenger]
+                            != self.config.points_to_win
+                        {
+                            Some(ChallengeWon(*challenger))
+                        } else {
+                            Some(ChallengeWonGameWon(*challenger))
+                        };
                     } else {
-                        panic!("ChallengeStarted pending event but state isn't Challenging");
-                    }
-                }
-                ChallengerChoseSkull {
-                    challenger,
-                    skull_player,
-                } => {
-                    // Transition back to playing
-                    self.state = State::Playing {
-                        current_player: skull_player,
-                    };
-                    self.reset_cards_played();
-                    if !self.is_player_out(challenger) {
+                        // Nothing exciting has happened, challenger needs
+                        // to continue flipping cards
                         self.pending_event = None;
-                    } else {
-                        // Got themselves out, sad horn (skip them)
-                        if challenger == skull_player {
-                            self.increment_player();
-                        }
-                        self.pending_event = Some(PlayerOut(challenger));
                     }
+                } else {
+                    return Err(EngineError::InvalidState(
+                        "Challenge event pending but game isn't challenging",
+                    ));
+                }
+            }
+            ChallengerChoseSkull {
+                challenger,
+                skull_player,
+            } => match self.config.discard_mode {
+                DiscardMode::Random => {
+                    // Card was discarded when the skull was flipped
+                    self.end_lost_challenge(challenger, skull_player)?;
                 }
-                ChallengeWon(player) => {
-                    // Transition back to playing
-                    self.state = State::Playing {
-                        current_player: player,
+                DiscardMode::Chosen => {
+                    // Wait for a card to be chosen
+                    self.state = State::Discarding {
+                        challenger,
+                        skull_player,
                     };
-                    self.reset_cards_played();
                     self.pending_event = None;
                 }
-                Input { .. } => unreachable!(
-                    "Input events should never be stored as a pending event"
-                ),
-                _ => self.pending_event = None, // No-ops: BidStarted, ChallengeWonGameWon, PlayerOut
+            },
+            ChallengeWon(player) => {
+                // Transition back to playing
+                self.state = State::Playing {
+                    current_player: player,
+                };
+                self.reset_cards_played();
+                self.round += 1;
+                self.pending_event = None;
             }
-            event
-        } else {
-            Event::Input {
-                player: self.player(),
-                input: match self.state {
-                    Playing { current_player } => {
-                        // Check player has cards to play,
-                        if self.cards_played[current_player].len()
-                            < self.player_hands[current_player].count() as usize
-                        {
-                            // if they do, see if they're allowed to start bidding.
-                            if self.cards_played_count() >= self.player_count()
-                            {
-                                PlayCardOrStartBid
-                            } else {
-                                PlayCard
-                            }
-                        } else {
-                            // if they don't, they must start bidding.
-                            StartBid
-                        }
+            Input { .. } => {
+                return Err(EngineError::InvalidState(
+                    "Input events should never be pending",
+                ))
+            }
+            PlayerOut(_) => {
+                // If everyone else is out, the last player standing wins
+                self.pending_event = if self.remaining_player_count() == 1 {
+                    let survivor = self
+                        .player_hands
+                        .iter()
+                        .position(|h| !h.empty())
+                        .ok_or(EngineError::AllPlayersOut)?;
+                    Some(LastPlayerStanding(survivor))
+                } else {
+                    None
+                };
+            }
+            ChallengeWonGameWon(winner) => {
+                self.state = State::Finished {
+                    winner,
+                    reason: WinReason::ChallengesWon,
+                };
+                self.pending_event = None;
+            }
+            LastPlayerStanding(winner) => {
+                self.state = State::Finished {
+                    winner,
+                    reason: WinReason::LastPlayerStanding,
+                };
+                self.pending_event = None;
+            }
+            _ => self.pending_event = None, // No-ops: BidStarted
+        }
+        Ok(())
+    }
+
+    // The type of input the current player needs to give
+    fn input_type(&self) -> InputType {
+        use InputType::*;
+        match self.state {
+            Playing { current_player } => {
+                // Check player has cards to play,
+                if self.cards_played[current_player].len()
+                    < self.player_hands[current_player].count() as usize
+                {
+                    // if they do, see if they're allowed to start bidding.
+                    if self.can_start_bid() {
+                        PlayCardOrStartBid
+                    } else {
+                        PlayCard
                     }
-                    Bidding { .. } => BidOrPass,
-                    Challenging { .. } => FlipCard,
-                },
+                } else {
+                    // if they don't, they must start bidding.
+                    StartBid
+                }
             }
+            Bidding { .. } => BidOrPass,
+            Challenging { .. } => FlipCard,
+            Discarding { .. } => ChooseDiscard,
+            Finished { .. } => unreachable!("Game is over"),
         }
     }
 
+    // Bidding can start once everyone still in has played a card
+    fn can_start_bid(&self) -> bool {
+        self.cards_played_count() >= self.remaining_player_count()
+    }
+
     /// Provide an input to the game simulation
     ///
     /// If the input is valid, Ok will be returned. Otherwise, you'll get a
-    /// [`ResponseError`] indicating what's been done incorrectly
+    /// [`ResponseError`] indicating what's been done incorrectly, and the
+    /// game is left unchanged.
+    /// Use [`validate()`](Game::validate()) to check a response without
+    /// applying it
     ///
     /// See also: [`Response`]
     pub fn respond(&mut self, response: Response) -> Result<(), ResponseError> {
+        let player = self.player();
+        // A discard ends the round, so record the round it was made in
+        let round = self.round;
+        let snapshot = self.snapshot();
+        if let Err(err) = self.apply_response(response) {
+            // An engine error can happen part way through applying the
+            // response, so put the game back how it was
+            self.restore(snapshot);
+            return Err(err);
+        }
+        if let Response::Flip(..) | Response::Discard(_) = response {
+            // Revealed information can't be taken back
+            self.undo_stack.clear();
+        } else if self.undo_depth > 0 {
+            if self.undo_stack.len() == self.undo_depth {
+                self.undo_stack.pop_front();
+            }
+            self.undo_stack.push_back(snapshot);
+        }
+        self.history.push(HistoryEntry {
+            round,
+            player,
+            item: HistoryItem::Response(response),
+        });
+        Ok(())
+    }
+
+    /// Gets every [`Response`] the current player could give that
+    /// [`respond()`](Game::respond()) would accept
+    ///
+    /// This is empty if there's a pending event to process with
+    /// [`what_next()`](Game::what_next()), if the game is over, or if the
+    /// game is in an inconsistent state
+    ///
+    /// Allocates a `Vec`
+    pub fn legal_responses(&self) -> Vec<Response> {
+        use Response::*;
+        if self.pending_event.is_some() {
+            return Vec::new();
+        }
+        match &self.state {
+            Playing { current_player } => {
+                let cards_remaining =
+                    match self.cards_remaining(*current_player) {
+                        Ok(hand) => hand,
+                        Err(_) => return Vec::new(),
+                    };
+                let mut responses = Card::ALL
+                    .iter()
+                    .filter(|card| cards_remaining.has(**card))
+                    .map(|card| PlayCard(*card))
+                    .collect::<Vec<_>>();
+                if self.can_start_bid() {
+                    responses.extend((1..=self.cards_played_count()).map(Bid));
+                }
+                responses
+            }
+            Bidding {
+                highest_bid,
+                max_bid,
+                ..
+            } => (highest_bid + 1..=*max_bid)
+                .map(Bid)
+                .chain(std::iter::once(Pass))
+                .collect(),
+            Challenging {
+                challenger,
+                flipped,
+                ..
+            } => self
+                .cards_played
+                .iter()
+                .enumerate()
+                .filter(|(player_index, _)| {
+                    player_index != challenger
+                        || !self.config.challenger_flips_own_first
+                })
+                .flat_map(|(player_index, played)| {
+                    (0..played.len())
+                        .filter(move |card_index| {
+                            !flipped[player_index].contains(card_index)
+                        })
+                        .map(move |card_index| Flip(player_index, card_index))
+                })
+                .collect(),
+            Discarding { challenger, .. } => (0
+                ..self.player_hands[*challenger].count() as usize)
+                .map(Discard)
+                .collect(),
+            Finished { .. } => Vec::new(),
+        }
+    }
+
+    /// Checks whether [`respond()`](Game::respond()) would accept the given
+    /// response, without changing the game
+    ///
+    /// If it wouldn't, you'll get the same [`ResponseError`] that `respond()`
+    /// would give
+    pub fn validate(&self, response: Response) -> Result<(), ResponseError> {
         use ResponseError::*;
         if self.pending_event.is_some() {
             return Err(PendingEvent);
+        } else if let Finished { .. } = self.state {
+            return Err(GameOver);
         }
 
-        // These both have to be worked out before we start working mutably
-        // with Game, even though they aren't always used
-        let player_count = self.player_count();
-        let played_count = self.cards_played_count();
-
         use Response::*;
-        match (&mut self.state, response) {
+        match (&self.state, response) {
             // Playing card
             (Playing { current_player }, PlayCard(card)) => {
                 /*
@@ -234,30 +629,149 @@ impl<const N: usize> Game<N> {
                 see if the card they're currently trying to play is in that set
                 of remaining cards
                  */
-                let cards_remaining = (self.player_hands[*current_player]
-                    - self.cards_played[*current_player].as_slice())
-                .unwrap_or_else(|err| panic!("{}", err));
-                if !cards_remaining.has(card) {
-                    return Err(CardNotInHand);
-                }
-                // We're all good, play the card
+                let cards_remaining = self.cards_remaining(*current_player)?;
+                ensure!(
+                    cards_remaining.has(card),
+                    CardNotInHand {
+                        player: *current_player,
+                        card
+                    }
+                );
+            }
+            // Starting bid
+            (Playing { .. }, Bid(n)) => {
+                ensure!(
+                    self.can_start_bid(),
+                    IncorrectInputType {
+                        expected: InputType::PlayCard,
+                        response
+                    }
+                );
+                ensure!(n > 0, BidTooLow { bid: n, min: 1 });
+                let max = self.cards_played_count();
+                ensure!(n <= max, BidTooHigh { bid: n, max });
+            }
+            // Raising bid
+            (
+                Bidding {
+                    highest_bid,
+                    max_bid,
+                    ..
+                },
+                Bid(n),
+            ) => {
+                ensure!(
+                    n <= *max_bid,
+                    BidTooHigh {
+                        bid: n,
+                        max: *max_bid
+                    }
+                );
+                ensure!(
+                    n > *highest_bid,
+                    BidTooLow {
+                        bid: n,
+                        min: *highest_bid + 1
+                    }
+                );
+            }
+            // Player passes on bid
+            (Bidding { .. }, Pass) => {}
+            // Challenger flips a card
+            (
+                Challenging {
+                    challenger,
+                    flipped,
+                    ..
+                },
+                Flip(player_index, card_index),
+            ) => {
+                ensure!(
+                    player_index < self.player_count(),
+                    InvalidPlayerIndex {
+                        player: player_index,
+                        player_count: self.player_count()
+                    }
+                );
+                let len = self.cards_played[player_index].len();
+                ensure!(
+                    card_index < len,
+                    InvalidIndex {
+                        player: player_index,
+                        index: card_index,
+                        len
+                    }
+                );
+                ensure!(
+                    player_index != *challenger
+                        || !self.config.challenger_flips_own_first,
+                    ManuallyFlippingOwnCards {
+                        player: player_index,
+                        index: card_index
+                    }
+                );
+                ensure!(
+                    !flipped[player_index].contains(&card_index),
+                    CardAlreadyFlipped {
+                        player: player_index,
+                        index: card_index
+                    }
+                );
+            }
+            // Skull player chooses which of the challenger's cards to discard
+            (Discarding { challenger, .. }, Discard(card_index)) => {
+                let len = self.player_hands[*challenger].count() as usize;
+                ensure!(
+                    card_index < len,
+                    InvalidIndex {
+                        player: *challenger,
+                        index: card_index,
+                        len
+                    }
+                );
+            }
+            _ => {
+                return Err(IncorrectInputType {
+                    expected: self.input_type(),
+                    response,
+                })
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_response(
+        &mut self,
+        response: Response,
+    ) -> Result<(), ResponseError> {
+        // Only an engine error can happen after this (which respond() recovers
+        // from), so any checks belong in validate()
+        self.validate(response)?;
+
+        // These have to be worked out before we start working mutably with
+        // Game, even though they aren't always used
+        let played_count = self.cards_played_count();
+        let remaining_count = self.remaining_player_count();
+
+        use Response::*;
+        match (&mut self.state, response) {
+            // Playing card
+            (Playing { current_player }, PlayCard(card)) => {
                 self.cards_played[*current_player].push(card).unwrap();
-                self.increment_player();
+                self.increment_player()?;
             }
             // Starting bid
             (Playing { current_player }, Bid(n)) => {
-                if n > played_count {
-                    return Err(BidTooHigh(self.cards_played_count()));
-                }
-
                 if n < played_count {
                     self.state = State::Bidding {
-                        current_bidder: (*current_player + 1) % player_count,
+                        current_bidder: *current_player,
                         highest_bid: n,
                         highest_bidder: *current_player,
                         max_bid: played_count,
                         passed: [false; N],
                     };
+                    // Skips any players that are out
+                    self.increment_player()?;
                     self.pending_event = Some(BidStarted);
                 } else {
                     // Start bid on max, instantly start challenge
@@ -280,26 +794,20 @@ impl<const N: usize> Game<N> {
                 },
                 Bid(n),
             ) => {
-                if n > *max_bid {
-                    return Err(BidTooHigh(*max_bid));
-                } else if n <= *highest_bid {
-                    return Err(BidTooLow(*highest_bid + 1));
-                } else {
-                    // Set the new highest bid(der)
-                    *highest_bid = n;
-                    *highest_bidder = *current_bidder;
-
-                    // Check if bid is at max and start challenge if so
-                    if highest_bid == max_bid {
-                        self.pending_event = Some(ChallengeStarted);
-                        self.state = Challenging {
-                            challenger: *highest_bidder,
-                            target: *highest_bid,
-                            flipped: [Self::STATE_FLIPPED_INIT; N],
-                        }
-                    } else {
-                        self.increment_player();
+                // Set the new highest bid(der)
+                *highest_bid = n;
+                *highest_bidder = *current_bidder;
+
+                // Check if bid is at max and start challenge if so
+                if highest_bid == max_bid {
+                    self.pending_event = Some(ChallengeStarted);
+                    self.state = Challenging {
+                        challenger: *highest_bidder,
+                        target: *highest_bid,
+                        flipped: [Self::STATE_FLIPPED_INIT; N],
                     }
+                } else {
+                    self.increment_player()?;
                 }
             }
             // Player passes on bid
@@ -319,7 +827,9 @@ impl<const N: usize> Game<N> {
                 );
                 passed[*current_bidder] = true;
                 // If all players apart from the highest bidder have passed
-                if passed.iter().filter(|b| **b).count() == N - 1 {
+                // (players that are out never get to pass)
+                if passed.iter().filter(|b| **b).count() == remaining_count - 1
+                {
                     self.pending_event = Some(ChallengeStarted);
                     self.state = Challenging {
                         challenger: *highest_bidder,
@@ -327,7 +837,7 @@ impl<const N: usize> Game<N> {
                         flipped: [Self::STATE_FLIPPED_INIT; N],
                     }
                 } else {
-                    self.increment_player();
+                    self.increment_player()?;
                 }
             }
             // Challenger flips a card
@@ -339,47 +849,51 @@ impl<const N: usize> Game<N> {
                 },
                 Flip(player_index, card_index),
             ) => {
-                if player_index >= player_count
-                    || card_index >= self.cards_played[player_index].len()
-                {
-                    return Err(InvalidIndex);
-                } else if player_index == *challenger {
-                    return Err(ManuallyFlippingOwnCards);
-                } else if flipped[player_index].contains(&card_index) {
-                    return Err(CardAlreadyFlipped);
-                }
-
                 let card_flipped = self.cards_played[player_index][card_index];
-                use Card::*;
-                match card_flipped {
-                    Skull => {
-                        self.player_hands[*challenger].discard_one();
-                        self.pending_event = Some(ChallengerChoseSkull {
-                            challenger: *challenger,
-                            skull_player: player_index,
-                        });
-                    }
-                    Flower => {
-                        flipped[player_index].push(card_index).unwrap();
-                        if len_2d(flipped) == *target {
-                            self.scores[*challenger] += 1;
-                            self.pending_event =
-                                Some(if self.scores[*challenger] == 2 {
-                                    ChallengeWonGameWon(*challenger)
-                                } else {
-                                    ChallengeWon(*challenger)
-                                });
-                        }
+                flipped[player_index].push(card_index).unwrap();
+                if card_flipped.loses_challenge() {
+                    if self.config.discard_mode == DiscardMode::Random {
+                        self.player_hands[*challenger]
+                            .discard_one(&mut self.rng);
                     }
+                    self.pending_event = Some(ChallengerChoseSkull {
+                        challenger: *challenger,
+                        skull_player: player_index,
+                    });
+                } else if len_2d(flipped) == *target {
+                    // Every other card flipped counts towards the target
+                    self.scores[*challenger] += 1;
+                    self.pending_event = Some(
+                        if self.scores[*challenger] == self.config.points_to_win
+                        {
+                            ChallengeWonGameWon(*challenger)
+                        } else {
+                            ChallengeWon(*challenger)
+                        },
+                    );
                 }
             }
-            _ => {
-                if let Input { input, .. } = self.what_next() {
-                    return Err(IncorrectInputType(input));
-                } else {
-                    unreachable!("Game must be waiting on an input at there's no pending event");
+            // Skull player chooses which of the challenger's cards to discard
+            (
+                Discarding {
+                    challenger,
+                    skull_player,
+                },
+                Discard(card_index),
+            ) => {
+                let (challenger, skull_player) = (*challenger, *skull_player);
+                let hand = self.player_hands[challenger];
+                let mut cards = hand.as_vec();
+                if challenger != skull_player {
+                    // The skull player chooses blindly
+                    self.rng.shuffle(&mut cards);
                 }
+                let discarded = cards[card_index];
+                self.player_hands[challenger] = (hand - &[discarded][..])
+                    .expect("Discarded card should be in hand");
+                self.end_lost_challenge(challenger, skull_player)?;
             }
+            _ => unreachable!("Response should have been validated"),
         }
         Ok(())
     }
@@ -389,6 +903,15 @@ impl<const N: usize> Game<N> {
         N
     }
 
+    /// Gets the index of the player who has won the game, if it's over
+    pub const fn winner(&self) -> Option<usize> {
+        if let Finished { winner, .. } = self.state {
+            Some(winner)
+        } else {
+            None
+        }
+    }
+
     /// Gets the number of players that still have cards
     pub fn remaining_player_count(&self) -> usize {
         self.player_hands.iter().filter(|h| !h.empty()).count()
@@ -400,6 +923,8 @@ impl<const N: usize> Game<N> {
             Playing { current_player } => current_player,
             Bidding { current_bidder, .. } => current_bidder,
             Challenging { challenger, .. } => challenger,
+            Discarding { skull_player, .. } => skull_player,
+            Finished { winner, .. } => winner,
         }
     }
 
@@ -410,6 +935,71 @@ impl<const N: usize> Game<N> {
             // This is almost certainly *not* what I want to do, but I've included it
             // for completeness
             Challenging { challenger, .. } => *challenger = player_index,
+            Discarding { skull_player, .. } => *skull_player = player_index,
+            Finished { winner, .. } => *winner = player_index,
+        }
+    }
+
+    fn snapshot(&self) -> Snapshot<N> {
+        Snapshot {
+            scores: self.scores,
+            player_hands: self.player_hands,
+            cards_played: self.cards_played.clone(),
+            state: self.state.clone(),
+            pending_event: self.pending_event,
+            round: self.round,
+            history_len: self.history.len(),
+            rng: self.rng,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot<N>) {
+        self.scores = snapshot.scores;
+        self.player_hands = snapshot.player_hands;
+        self.cards_played = snapshot.cards_played;
+        self.state = snapshot.state;
+        self.pending_event = snapshot.pending_event;
+        self.round = snapshot.round;
+        self.history.truncate(snapshot.history_len);
+        self.rng = snapshot.rng;
+    }
+
+    // Transitions back to playing once the challenger has lost a card
+    fn end_lost_challenge(
+        &mut self,
+        challenger: usize,
+        skull_player: usize,
+    ) -> Result<(), EngineError> {
+        self.state = State::Playing {
+            current_player: skull_player,
+        };
+        self.reset_cards_played();
+        self.round += 1;
+        if !self.is_player_out(challenger) {
+            self.pending_event = None;
+        } else {
+            // Got themselves out, sad horn (skip them)
+            if challenger == skull_player {
+                self.increment_player()?;
+            }
+            self.pending_event = Some(PlayerOut(challenger));
+        }
+        Ok(())
+    }
+
+    // The player an event is about, for recording in the history
+    fn event_player(&self, event: Event) -> usize {
+        match event {
+            BidStarted => match self.state {
+                Bidding { highest_bidder, .. } => highest_bidder,
+                _ => self.player(),
+            },
+            ChallengerChoseSkull { challenger, .. } => challenger,
+            PlayerOut(player)
+            | ChallengeWon(player)
+            | ChallengeWonGameWon(player)
+            | LastPlayerStanding(player) => player,
+            ChallengeStarted | Input { .. } => self.player(),
         }
     }
 
@@ -422,14 +1012,19 @@ impl<const N: usize> Game<N> {
         }
     }
 
-    fn increment_player(&mut self) {
-        // Pre-flight checks
-        assert!(
+    fn increment_player(&mut self) -> Result<(), EngineError> {
+        // Pre-flight checks, all players being out would loop forever
+        ensure!(
             !self.player_hands.iter().all(|h| h.empty()),
-            "All players are out, panicking to avoid infinite loop"
+            EngineError::AllPlayersOut
         );
         debug_assert!(
-            !matches!(self.state, State::Challenging { .. }),
+            !matches!(
+                self.state,
+                State::Challenging { .. }
+                    | State::Discarding { .. }
+                    | State::Finished { .. }
+            ),
             "Increment player should never be called when challenging"
         );
 
@@ -445,6 +1040,7 @@ impl<const N: usize> Game<N> {
             }
         }
         self.set_player(player_index);
+        Ok(())
     }
 
     fn is_player_out(&self, player_index: usize) -> bool {
@@ -454,6 +1050,20 @@ impl<const N: usize> Game<N> {
             .empty()
     }
 
+    // The cards in a player's hand that they haven't played yet
+    fn cards_remaining(
+        &self,
+        player_index: usize,
+    ) -> Result<Hand, EngineError> {
+        (self.player_hands[player_index]
+            - self.cards_played[player_index].as_slice())
+        .map_err(|_| {
+            EngineError::InvalidState(
+                "Player has played cards that aren't in their hand",
+            )
+        })
+    }
+
     fn cards_played_count(&self) -> usize {
         self.cards_played.iter().map(|fv| fv.len()).sum()
     }
@@ -471,85 +1081,283 @@ impl<const N: usize> Game<N> {
         self.cards_played = [EMPTY; N];
     }
 
+    /// Checks the game is in a consistent state, which the engine relies on
+    ///
+    /// Games created and played through the engine are always consistent,
+    /// so this is only needed for games from elsewhere, e.g. deserialized
+    /// ones.
+    /// Returns the first invariant found not to hold, see
+    /// [`validate_state()`](Game::validate_state()) for all of them
+    pub fn check_invariants(&self) -> Result<(), EngineError> {
+        self.check_valid().map_err(EngineError::InvalidState)
+    }
+
+    /// Checks the game is in a consistent state, which the engine relies on
+    ///
+    /// Unlike [`check_invariants()`](Game::check_invariants()), every
+    /// invariant that doesn't hold is reported.
+    /// Allocates a `Vec`
+    pub fn validate_state(&self) -> Result<(), Vec<InvariantViolation>> {
+        let violations = self.invariant_violations();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
     // Motto: assume nothing, check if game state is valid
-    // When I actually hit stable releases, this should only be needed for ensuring
-    // Game::create_from isn't being abused. For now though, it'll be used a lot
-    fn assert_valid(&self) {
-        assert!(
-            !self.scores.iter().any(|s| *s > 2),
-            "No one should have a score of more than 2"
+    // Gives the reason for the first check that fails
+    pub(crate) fn check_valid(&self) -> Result<(), &'static str> {
+        match self.invariant_violations().first() {
+            Some(violation) => Err(violation.description()),
+            None => Ok(()),
+        }
+    }
+
+    // Every invariant that doesn't hold, in the order they're checked.
+    // Checks that would panic if an earlier one failed (e.g. out of range
+    // indexes) are skipped
+    fn invariant_violations(&self) -> Vec<InvariantViolation> {
+        use InvariantViolation::*;
+        let mut violations = Vec::new();
+        // Records a violation if the condition is false, and evaluates to
+        // the condition
+        macro_rules! check {
+            ($cond:expr, $violation:expr) => {{
+                let holds = $cond;
+                if !holds {
+                    violations.push($violation);
+                }
+                holds
+            }};
+        }
+        let in_range = |player_index: usize| player_index < N;
+        // A challenger who flipped a skull loses a card as soon as they flip
+        // it when discarding randomly, which may be one they played
+        let lost_card = |player_index: usize| {
+            self.config.discard_mode == DiscardMode::Random
+                && matches!(
+                    self.pending_event,
+                    Some(ChallengerChoseSkull { challenger, .. })
+                        if challenger == player_index
+                )
+        };
+
+        if let Err(reason) = self.config.check_valid(N) {
+            violations.push(Config(reason));
+        }
+        check!(
+            !self.scores.iter().any(|s| *s > self.config.points_to_win),
+            Scores("No one should have a score of more than the points to win")
         );
 
         // Ensure hands are valid
-        self.player_hands.iter().for_each(|h| h.assert_valid());
+        check!(
+            self.player_hands.iter().all(|h| h.is_valid()),
+            Hands("Too many cards in hand")
+        );
+        check!(
+            self.player_hands.iter().all(|h| self.config.allows(*h)),
+            Hands("Hand has cards that weren't in the starting hand")
+        );
+        let anyone_in = check!(
+            self.remaining_player_count() > 0,
+            Hands("All players are out")
+        );
 
         // Ensure cards played are valid
-        self.cards_played.iter()
-            .enumerate()
-            .for_each(|(player_index, ordered_cards)| {
-                // Check cards played are legal
-                let played_hand = Hand::try_from(ordered_cards.as_slice());
-                let played_hand = played_hand.expect("Played cards make invalid hand");
+        for (player_index, ordered_cards) in
+            self.cards_played.iter().enumerate()
+        {
+            // Check cards played are legal
+            if let Ok(played_hand) = Hand::try_from(ordered_cards.as_slice()) {
+                let hand = self.player_hands[player_index];
+                let missing = Card::ALL
+                    .iter()
+                    .map(|card| {
+                        played_hand
+                            .count_of(*card)
+                            .saturating_sub(hand.count_of(*card))
+                    })
+                    .sum::<u8>();
                 // Check cards played could have been played from player's hand
-                assert!(
-                    self.player_hands[player_index].is_superset_of(played_hand),
-                    "Player has cards on the table that they shouldn't, based on the cards available to them"
+                check!(
+                    missing == 0 || (missing == 1 && lost_card(player_index)),
+                    CardsPlayed("Player has cards on the table that they shouldn't, based on the cards available to them")
                 );
-            });
-        if !matches!(self.state, Playing { .. }) {
-            // Only required if bidding or challenging
-            assert!(
+            } else {
+                violations.push(CardsPlayed("Played cards make invalid hand"));
+            }
+        }
+        if !matches!(self.state, Playing { .. } | Finished { .. }) {
+            // Only required if bidding, challenging or discarding
+            check!(
                 self.cards_played_count() >= self.remaining_player_count(),
-                "Less cards played than there are players"
+                CardsPlayed("Less cards played than there are players")
             );
         }
 
+        // Ensure the pending event makes sense
+        let event_in_range = match self.pending_event {
+            Some(Input { .. }) => {
+                violations
+                    .push(PendingEvent("Input events should never be pending"));
+                true
+            }
+            Some(BidStarted) => check!(
+                matches!(self.state, Bidding { .. }),
+                PendingEvent("Bid started but game isn't bidding")
+            ),
+            Some(ChallengeStarted) => check!(
+                matches!(self.state, Challenging { .. }),
+                PendingEvent(
+                    "Challenge event pending but game isn't challenging"
+                )
+            ),
+            Some(ChallengerChoseSkull {
+                challenger,
+                skull_player,
+            }) => {
+                if let Challenging {
+                    challenger: challenging,
+                    ..
+                } = self.state
+                {
+                    check!(
+                        in_range(challenger) && in_range(skull_player),
+                        PendingEvent(
+                            "Player index out of range in pending event"
+                        )
+                    ) && check!(
+                        challenger == challenging,
+                        PendingEvent(
+                            "Player who flipped a skull isn't the challenger"
+                        )
+                    )
+                } else {
+                    violations.push(PendingEvent(
+                        "Challenge event pending but game isn't challenging",
+                    ));
+                    false
+                }
+            }
+            Some(
+                PlayerOut(player_index)
+                | ChallengeWon(player_index)
+                | ChallengeWonGameWon(player_index)
+                | LastPlayerStanding(player_index),
+            ) => check!(
+                in_range(player_index),
+                PendingEvent("Player index out of range in pending event")
+            ),
+            None => true,
+        };
+        if let Some(PlayerOut(player_index)) = self.pending_event {
+            if event_in_range {
+                check!(
+                    self.is_player_out(player_index),
+                    PendingEvent("Player declared out still has cards")
+                );
+            }
+        }
+        if let Some(ChallengerChoseSkull { skull_player, .. }) =
+            self.pending_event
+        {
+            if event_in_range {
+                check!(
+                    self.cards_played[skull_player]
+                        .iter()
+                        .any(|card| card.loses_challenge()),
+                    PendingEvent("Skull player hasn't played a skull")
+                );
+            }
+        }
+
         // Ensure scores is valid
-        let players_with_winning_score =
-            self.scores.iter().filter(|s| **s == 2).count();
-        if let Some(ChallengeWonGameWon(winner_index)) = self.pending_event {
-            assert_eq!(
-                players_with_winning_score, 1,
-                "One player was expected to have a winning score"
-            );
-            assert!(
-                !self.is_player_out(winner_index),
-                "Winning player has no cards, meaning they are out"
+        let players_with_winning_score = self
+            .scores
+            .iter()
+            .filter(|s| **s == self.config.points_to_win)
+            .count();
+        let challenges_winner = match (self.pending_event, &self.state) {
+            (Some(ChallengeWonGameWon(winner_index)), _)
+            | (
+                _,
+                &Finished {
+                    winner: winner_index,
+                    reason: WinReason::ChallengesWon,
+                },
+            ) => Some(winner_index),
+            _ => None,
+        };
+        if let Some(winner_index) = challenges_winner {
+            check!(
+                players_with_winning_score == 1,
+                Scores("One player was expected to have a winning score")
             );
+            if check!(
+                in_range(winner_index),
+                Scores("Winner index out of range")
+            ) {
+                check!(
+                    !self.is_player_out(winner_index),
+                    Scores("Winning player has no cards, meaning they are out")
+                );
+            }
         } else {
-            assert_eq!(
-                players_with_winning_score, 0,
-                "No players were expected to have a winning score"
+            check!(
+                players_with_winning_score == 0,
+                Scores("No players were expected to have a winning score")
+            );
+        }
+
+        // Ensure the last player standing has been declared correctly
+        if let Some(LastPlayerStanding(winner_index)) = self.pending_event {
+            check!(
+                self.remaining_player_count() == 1,
+                PendingEvent("Last player standing declared with multiple players remaining")
             );
+            if event_in_range {
+                check!(
+                    !self.is_player_out(winner_index),
+                    PendingEvent("Last player standing has no cards, meaning they are out")
+                );
+            }
         }
 
         // Ensure <=1 difference in number of cards played per player, ignoring
         // players that are out
-        let mut number_of_cards_played = self
-            .cards_played
-            .iter()
-            .enumerate()
-            .filter(|(i, _)| !self.is_player_out(*i))
-            .map(|(_, fv)| fv.len())
-            .collect::<FVec<usize, N>>();
-        number_of_cards_played.sort_unstable();
-        assert!(
-            number_of_cards_played[self.remaining_player_count() - 1]
-                - number_of_cards_played[0]
-                <= 1,
-            "Some players have played 2+ more cards than others",
-        );
+        if anyone_in {
+            let mut number_of_cards_played = self
+                .cards_played
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !self.is_player_out(*i))
+                .map(|(_, fv)| fv.len())
+                .collect::<FVec<usize, N>>();
+            number_of_cards_played.sort_unstable();
+            check!(
+                number_of_cards_played[self.remaining_player_count() - 1]
+                    - number_of_cards_played[0]
+                    <= 1,
+                CardsPlayed(
+                    "Some players have played 2+ more cards than others"
+                )
+            );
+        }
 
         match &self.state {
             Playing { current_player } => {
-                assert!(
-                    *current_player < self.scores.len(),
-                    "Current player index out of range"
-                );
-                assert!(
-                    !self.is_player_out(*current_player),
-                    "Current player mustn't be out"
-                );
+                if check!(
+                    in_range(*current_player),
+                    State("Current player index out of range")
+                ) {
+                    check!(
+                        !self.is_player_out(*current_player),
+                        State("Current player mustn't be out")
+                    );
+                }
             }
             Bidding {
                 current_bidder,
@@ -558,148 +1366,224 @@ impl<const N: usize> Game<N> {
                 max_bid,
                 passed,
             } => {
-                assert!(
-                    *current_bidder < self.scores.len(),
-                    "Current bidder index out of range"
-                );
-                assert!(
-                    !self.is_player_out(*current_bidder),
-                    "Current bidder mustn't be out"
-                );
-                assert!(
+                if check!(
+                    in_range(*current_bidder),
+                    State("Current bidder index out of range")
+                ) {
+                    check!(
+                        !self.is_player_out(*current_bidder),
+                        State("Current bidder mustn't be out")
+                    );
+                }
+                check!(
                     current_bid < max_bid,
-                    "Current bid must be strictly less than maximum (else a challenge should have started"
-                );
-                assert!(
-                    *highest_bidder < self.scores.len(),
-                    "Highest bidder out of range"
-                );
-                assert!(
-                    !self.is_player_out(*highest_bidder),
-                    "Current bidder mustn't be out"
+                    State("Current bid must be strictly less than maximum (else a challenge should have started")
                 );
-                assert_ne!(
-                    current_bidder, highest_bidder,
-                    "Current and highest bidder mustn't be same person"
+                if check!(
+                    in_range(*highest_bidder),
+                    State("Highest bidder out of range")
+                ) {
+                    check!(
+                        !self.is_player_out(*highest_bidder),
+                        State("Current bidder mustn't be out")
+                    );
+                }
+                check!(
+                    current_bidder != highest_bidder,
+                    State("Current and highest bidder mustn't be same person")
                 );
                 // At most all but two players can have passed
-                assert!(
-                    passed.iter().filter(|b| **b).count()
-                        <= self.remaining_player_count() - 2,
-                    "Too many players have passed"
+                check!(
+                    passed.iter().filter(|b| **b).count() + 2
+                        <= self.remaining_player_count(),
+                    State("Too many players have passed")
                 );
             }
-            State::Challenging {
+            Challenging {
                 challenger,
                 target,
                 flipped,
             } => {
-                assert!(
-                    !self.is_player_out(*challenger),
-                    "Challenger mustn't be out"
-                );
-                assert!(
-                    *challenger < self.scores.len(),
-                    "Challenger index out of range"
+                let challenger_in_range = check!(
+                    in_range(*challenger),
+                    State("Challenger index out of range")
                 );
+                if challenger_in_range {
+                    check!(
+                        !self.is_player_out(*challenger)
+                            || lost_card(*challenger),
+                        State("Challenger mustn't be out")
+                    );
+                }
                 let cards_played = self.cards_played_count();
-                assert!(
+                check!(
                     *target <= cards_played,
-                    "Target larger than number of cards played"
+                    State("Target larger than number of cards played")
                 );
-                assert!(
+                check!(
                     *target >= self.cards_flipped_count().unwrap(),
-                    "More cards flipped than targetted"
+                    State("More cards flipped than targetted")
                 );
 
                 // Ensuring flipping is valid
-                flipped.iter().zip(self.cards_played.iter()).for_each(
-                    |(indexes, played)| {
-                        assert!(
-                            indexes.len() <= played.len(),
-                            "More cards flipped than there are cards"
-                        );
-                        // Ensure no flipped indexes exceed the number of cards played
-                        assert!(
-                            !indexes.iter().any(|i| *i >= played.len()),
-                            "Out of range index in cards flipped"
-                        );
-                        // Ensure all flipped indexes have no duplicates
-                        assert!(
-                            has_unique_elements(indexes),
-                            "Duplicate indexes given"
-                        );
-                    },
-                );
+                let mut flips_in_range = true;
+                for (indexes, played) in
+                    flipped.iter().zip(self.cards_played.iter())
+                {
+                    check!(
+                        indexes.len() <= played.len(),
+                        State("More cards flipped than there are cards")
+                    );
+                    // Ensure no flipped indexes exceed the number of cards played
+                    flips_in_range &= check!(
+                        !indexes.iter().any(|i| *i >= played.len()),
+                        State("Out of range index in cards flipped")
+                    );
+                    // Ensure all flipped indexes have no duplicates
+                    check!(
+                        has_unique_elements(indexes),
+                        State("Duplicate indexes given")
+                    );
+                }
 
                 // Ensure correct cards of challenger's are flipped
                 // (if the challenge has been announced)
-                if !matches!(self.pending_event, Some(ChallengeStarted)) {
-                    if *target <= self.cards_played[*challenger].len() {
+                if challenger_in_range
+                    && self.config.challenger_flips_own_first
+                    && !matches!(self.pending_event, Some(ChallengeStarted))
+                {
+                    let challenger_played_count =
+                        self.cards_played[*challenger].len();
+                    if *target <= challenger_played_count {
                         // Flipping subset of own cards
-                        let offset = 4 - *target;
-                        assert_eq!(
+                        let offset = challenger_played_count - *target;
+                        check!(
                             // Assume that flipped is sorted for own cards (low - high)
-                            flipped[*challenger].as_slice(),
-                            // Produces list from offset to one below number of cards
-                            // e.g. offset = 1, 4 cards: &[1, 2, 3]
-                            (offset..self.player_hands[*challenger].count() as usize).collect::<Vec<_>>().as_slice(),
-                            "Challenger hasn't flipped their own cards that they are required to flip"
+                            flipped[*challenger].as_slice()
+                                // Produces list from offset to one below number of cards
+                                // e.g. offset = 1, 4 cards: &[1, 2, 3]
+                                == (offset..challenger_played_count).collect::<Vec<_>>().as_slice(),
+                            State("Challenger hasn't flipped their own cards that they are required to flip")
                         );
                         if self.cards_played[*challenger][offset..]
-                            .contains(&Skull)
+                            .iter()
+                            .any(|card| card.loses_challenge())
                         {
-                            self.assert_self_skull_correctly_declared();
+                            self.check_self_skull_correctly_declared(
+                                &mut violations,
+                            );
                         }
                     } else {
                         // Flipping all of own cards
-                        assert_eq!(
-                            flipped[*challenger].len(),
-                            self.cards_played[*challenger].len(),
-                            "Challenger hasn't flipped all of their own cards when they needed to"
+                        check!(
+                            flipped[*challenger].len() == challenger_played_count,
+                            State("Challenger hasn't flipped all of their own cards when they needed to")
                         );
-                        if self.cards_played[*challenger].contains(&Skull) {
-                            self.assert_self_skull_correctly_declared();
+                        if self.cards_played[*challenger]
+                            .iter()
+                            .any(|card| card.loses_challenge())
+                        {
+                            self.check_self_skull_correctly_declared(
+                                &mut violations,
+                            );
                         }
                     }
                 }
 
                 // Ensure number of flipped skulls is correct
-                if let Some(ChallengerChoseSkull { .. }) = self.pending_event {
-                    assert_eq!(
-                        self.flipped_skulls(),
-                        1,
-                        "Expected one skull to have been flipped as there's a challenger chose skull pending event"
-                    );
-                } else {
-                    assert_eq!(
-                        self.flipped_skulls(),
-                        0,
-                        "Expected no skulls to have been flipped as there's no pending event"
-                    );
+                if flips_in_range {
+                    if let Some(ChallengerChoseSkull { .. }) =
+                        self.pending_event
+                    {
+                        check!(
+                            self.flipped_skulls() >= 1,
+                            PendingEvent("Expected a skull to have been flipped as there's a challenger chose skull pending event")
+                        );
+                    } else {
+                        check!(
+                            self.flipped_skulls() == 0,
+                            PendingEvent("Expected no skulls to have been flipped as there's no pending event")
+                        );
+                    }
                 }
 
                 // Ensure there's a pending event if target reached (challenge won)
-                if self.cards_flipped_count().unwrap() == *target {
-                    if self.scores[*challenger] != 2 {
-                        assert_eq!(
-                            self.pending_event,
-                            Some(ChallengeWon(*challenger)),
-                            "Challenge not declared as won or declared as won by incorrect player"
+                if challenger_in_range
+                    && flips_in_range
+                    && self.flipped_skulls() == 0
+                    && self.cards_flipped_count().unwrap() == *target
+                {
+                    if self.scores[*challenger] != self.config.points_to_win {
+                        check!(
+                            self.pending_event == Some(ChallengeWon(*challenger)),
+                            PendingEvent("Challenge not declared as won or declared as won by incorrect player")
                         );
                     } else {
-                        assert_eq!(
-                            self.pending_event,
-                            Some(ChallengeWonGameWon(*challenger)),
-                            "Challenge & game not declared as won or declared as won by incorrect player"
+                        check!(
+                            self.pending_event
+                                == Some(ChallengeWonGameWon(*challenger)),
+                            PendingEvent("Challenge & game not declared as won or declared as won by incorrect player")
                         );
                     }
                 }
             }
+            Discarding {
+                challenger,
+                skull_player,
+            } => {
+                if check!(
+                    in_range(*challenger),
+                    State("Challenger index out of range")
+                ) {
+                    check!(
+                        !self.is_player_out(*challenger),
+                        State("Challenger mustn't be out")
+                    );
+                }
+                if check!(
+                    in_range(*skull_player),
+                    State("Skull player index out of range")
+                ) {
+                    check!(
+                        self.cards_played[*skull_player]
+                            .iter()
+                            .any(|card| card.loses_challenge()),
+                        State("Skull player hasn't played a skull")
+                    );
+                }
+                check!(
+                    self.pending_event.is_none(),
+                    PendingEvent(
+                        "No events should be pending while discarding"
+                    )
+                );
+            }
+            Finished { winner, reason } => {
+                if check!(in_range(*winner), State("Winner index out of range"))
+                {
+                    check!(
+                        !self.is_player_out(*winner),
+                        State("Winner mustn't be out")
+                    );
+                }
+                if let WinReason::LastPlayerStanding = reason {
+                    check!(
+                        self.remaining_player_count() == 1,
+                        State("Last player standing won with multiple players remaining")
+                    );
+                }
+                check!(
+                    self.pending_event.is_none(),
+                    PendingEvent(
+                        "No events should be pending once the game is over"
+                    )
+                );
+            }
         }
+        violations
     }
 
-    // Only used in Game::assert_valid
+    // Only used in Game::invariant_violations
     fn flipped_skulls(&self) -> usize {
         if let State::Challenging { flipped, .. } = &self.state {
             flipped
@@ -708,7 +1592,7 @@ impl<const N: usize> Game<N> {
                 .map(|(indexes_flipped, cards_played)| {
                     indexes_flipped
                         .iter()
-                        .filter(|i| matches!(cards_played[**i], Skull))
+                        .filter(|i| cards_played[**i].loses_challenge())
                         .count()
                 })
                 .sum()
@@ -718,18 +1602,25 @@ impl<const N: usize> Game<N> {
     }
 
     // Only call if you know a skull has been turned that was played by the challenger
-    fn assert_self_skull_correctly_declared(&self) {
+    fn check_self_skull_correctly_declared(
+        &self,
+        violations: &mut Vec<InvariantViolation>,
+    ) {
+        use InvariantViolation::PendingEvent;
         if let Some(ChallengerChoseSkull {
             challenger,
             skull_player,
         }) = self.pending_event
         {
-            assert_eq!(
-                challenger, skull_player,
-                "Challenger chose own skull but pending event reports differently"
-            );
+            if challenger != skull_player {
+                violations.push(PendingEvent(
+                    "Challenger chose own skull but pending event reports differently",
+                ));
+            }
         } else {
-            panic!("Challenger chose own skull but event not pending for this");
+            violations.push(PendingEvent(
+                "Challenger chose own skull but event not pending for this",
+            ));
         }
     }
 
@@ -739,6 +1630,9 @@ impl<const N: usize> Game<N> {
     /// rely on this - submit a bug report if you can create an invalid game),
     /// and will panic if the game is in any way invalid.
     /// Otherwise, it returns you the newly created game
+    ///
+    /// See [`GameBuilder`] for setting only part of the position, and
+    /// getting an error instead of a panic
     pub fn create_from(
         scores: [u8; N],
         player_hands: [Hand; N],
@@ -746,17 +1640,36 @@ impl<const N: usize> Game<N> {
         state: State<N>,
         pending_event: Option<Event>,
     ) -> Self {
-        assert!((3..=6).contains(&N), "Invalid number of players");
-        let g = Game {
-            scores,
-            player_hands,
-            cards_played,
-            state,
-            pending_event,
-        };
-        g.assert_valid();
-        println!("Game is valid");
-        g
+        let mut builder = GameBuilder::new()
+            .scores(scores)
+            .hands(player_hands)
+            .cards_played(cards_played)
+            .state(state);
+        builder.pending_event = pending_event;
+        builder
+            .build()
+            .unwrap_or_else(|violations| panic!("{}", violations[0]))
+    }
+
+    pub(crate) fn from_builder(builder: GameBuilder<N>) -> Self {
+        let seed = builder.seed.unwrap_or_else(GameRng::entropy_seed);
+        Game {
+            scores: builder.scores,
+            player_hands: builder
+                .player_hands
+                .unwrap_or([builder.config.starting_hand; N]),
+            cards_played: builder.cards_played,
+            state: builder.state,
+            pending_event: builder.pending_event,
+            round: builder.round,
+            history: Vec::new(),
+            seed,
+            rng: GameRng::new(seed),
+            config: builder.config,
+            replayable: false,
+            undo_depth: 0,
+            undo_stack: VecDeque::new(),
+        }
     }
 }
 
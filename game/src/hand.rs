@@ -2,49 +2,55 @@ use std::ops::Sub;
 
 use crate::*;
 
-use nanorand::Rng;
 use std::error::Error;
 use HandError::*;
 
 /// A player's hand when playing Skull
 ///
-/// Can contain at most one [skull](Card::Skull), and at most 3
-/// [flowers](Card::Flower)
-///
+/// Can contain any combination of cards, up to [`MAX_HAND_SIZE`] cards in
+/// total.
+/// Which combinations a game allows is decided by its
+/// [starting hand](GameConfig::starting_hand), see [`GameConfig::allows()`]
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Hand {
-    skull: bool,
-    flowers: u8,
+    counts: [u8; Card::KINDS], // indexed by Card::index()
 }
 
 impl Hand {
     /// Creates a new full hand (1 skull, 3 flowers)
     // WARNING: new() and default() differ
     pub const fn new() -> Self {
-        Hand {
-            skull: true,
-            flowers: 3,
-        }
+        let mut counts = [0; Card::KINDS];
+        counts[Card::Flower.index()] = 3;
+        counts[Card::Skull.index()] = 1;
+        Hand { counts }
     }
 
     /// Returns `true` if there is a skull in the hand
     pub const fn has_skull(&self) -> bool {
-        self.skull
+        self.has(Card::Skull)
     }
 
     /// Returns `true` if the given card is present in the hand
     pub const fn has(&self, other: Card) -> bool {
-        use Card::*;
-        match other {
-            Skull => self.has_skull(),
-            Flower => self.flowers > 0,
-        }
+        self.count_of(other) > 0
+    }
+
+    /// Returns the number of the given card in the hand
+    pub const fn count_of(&self, card: Card) -> u8 {
+        self.counts[card.index()]
     }
 
     /// Returns the number of cards in the hand, as a [u8]
     pub const fn count(&self) -> u8 {
-        self.flowers + self.skull as u8
+        let mut count = 0;
+        let mut index = 0;
+        while index < Card::KINDS {
+            count += self.counts[index];
+            index += 1;
+        }
+        count
     }
 
     /// Returns true if there are no cards in the hand
@@ -52,54 +58,60 @@ impl Hand {
         self.count() == 0
     }
 
-    /// Represents the hand as a `FVec<Card, 4>`
+    /// Represents the hand as a `FVec<Card, MAX_HAND_SIZE>`, ordered as in
+    /// [`Card::ALL`] (flowers first)
     ///
     /// Useful for when you want to display the cards in a hand
     ///
     /// This method allocates a new [`FVec`]
-    pub fn as_vec(&self) -> FVec<Card, 4> {
-        use Card::*;
+    pub fn as_vec(&self) -> FVec<Card, MAX_HAND_SIZE> {
         let mut v = fvec![];
-        v.extend(std::iter::repeat(Flower).take(self.flowers as usize));
-        if self.skull {
-            v.push(Skull).expect("There were 4 flowers");
+        for card in Card::ALL {
+            for _ in 0..self.count_of(card) {
+                v.push(card).expect("Hand has too many cards");
+            }
         }
         v
     }
 
     /// Returns `true` if `other` is a subset of `self`
     pub(crate) fn is_superset_of(&self, other: Hand) -> bool {
-        let skull_ok =
-            self.skull == other.skull || (self.skull && !other.skull);
-        let flowers_ok = self.flowers >= other.flowers;
-        skull_ok && flowers_ok
+        self.counts
+            .iter()
+            .zip(other.counts.iter())
+            .all(|(ours, theirs)| ours >= theirs)
     }
 
     /// Discards a single random card from the hand
-    pub(crate) fn discard_one(&mut self) {
+    pub(crate) fn discard_one(&mut self, rng: &mut GameRng) {
         debug_assert!(
             self.count() > 0,
             "Tried to discard card with none in hand"
         );
 
-        if self.skull && self.flowers > 0 {
-            let choice = nanorand::tls_rng().generate_range(0..=self.count());
-            if choice == 0 {
-                self.skull = false;
-            } else {
-                self.flowers -= 1;
-            }
-        } else if self.skull {
-            self.skull = false
+        // Only use the rng if there's a choice to be made. Replays rely on
+        // the seed alone to repeat random discards, so the order cards are
+        // counted in must stay the same
+        let kinds_held = self.counts.iter().filter(|c| **c > 0).count();
+        let mut choice = if kinds_held > 1 {
+            rng.generate_range(0..self.count() as usize)
         } else {
-            self.flowers -= 1;
+            0
+        };
+        for card in Card::ALL.iter().rev() {
+            let count = &mut self.counts[card.index()];
+            if choice < *count as usize {
+                *count -= 1;
+                return;
+            }
+            choice -= *count as usize;
         }
     }
 
-    /// Checks the hand doesn't have an invalid number of flowers
-    /// (i.e. more than 4)
-    pub(crate) fn assert_valid(&self) {
-        assert!(self.flowers < 4, "Too many flowers in hand");
+    /// Checks the hand doesn't have too many cards
+    /// (i.e. more than [`MAX_HAND_SIZE`])
+    pub(crate) const fn is_valid(&self) -> bool {
+        self.count() as usize <= MAX_HAND_SIZE
     }
 }
 
@@ -108,8 +120,7 @@ impl Default for Hand {
     /// Creates a new empty hand
     fn default() -> Self {
         Hand {
-            skull: false,
-            flowers: 0,
+            counts: [0; Card::KINDS],
         }
     }
 }
@@ -124,28 +135,14 @@ impl TryFrom<&[Card]> for Hand {
     type Error = HandError;
 
     fn try_from(value: &[Card]) -> Result<Self, Self::Error> {
-        let mut skull = false;
-        let mut flowers = 0;
-        for n in value {
-            use Card::*;
-            match n {
-                Skull => {
-                    if !skull {
-                        skull = true
-                    } else {
-                        return Err(MultipleSkulls);
-                    }
-                }
-                Flower => {
-                    if flowers < 3 {
-                        flowers += 1
-                    } else {
-                        return Err(TooManyFlowers);
-                    }
-                }
-            }
+        if value.len() > MAX_HAND_SIZE {
+            return Err(TooManyCards);
+        }
+        let mut hand = Hand::default();
+        for card in value {
+            hand.counts[card.index()] += 1;
         }
-        Ok(Hand { skull, flowers })
+        Ok(hand)
     }
 }
 
@@ -157,6 +154,29 @@ impl<const N: usize> TryFrom<[Card; N]> for Hand {
     }
 }
 
+// Represented as the list of cards in the hand, e.g. ["Flower", "Skull"]
+#[cfg(feature = "serde")]
+impl serde::Serialize for Hand {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        self.as_vec().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Hand {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let cards = Vec::<Card>::deserialize(deserializer)?;
+        Hand::try_from(cards.as_slice()).map_err(serde::de::Error::custom)
+    }
+}
+
+// Taking cards from a hand the rules allow always leaves a hand they allow
+// (see GameConfig::allows()), so only the cards taken need checking
 impl Sub<Self> for Hand {
     type Output = Result<Hand, HandError>;
 
@@ -164,20 +184,12 @@ impl Sub<Self> for Hand {
         if !self.is_superset_of(rhs) {
             Err(RhsNotSubset(self, rhs))
         } else {
-            /*
-            Truth table for skull:
-            LHS     RHS     Output
-             F       F        F
-             F       T        Err
-             T       F        T
-             T       T        F
-            Because the Err condition has already been checked, we can just XOR
-            (^) here
-             */
-            let skull = self.skull ^ rhs.skull;
+            let mut counts = self.counts;
             // Subtraction doesn't need to be checked because of check
-            let flowers = self.flowers - rhs.flowers;
-            Ok(Hand { skull, flowers })
+            for (count, other) in counts.iter_mut().zip(rhs.counts.iter()) {
+                *count -= other;
+            }
+            Ok(Hand { counts })
         }
     }
 }
@@ -191,20 +203,27 @@ impl Sub<&[Card]> for Hand {
     }
 }
 
+/// The reasons a [`Hand`] can't be made
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum HandError {
-    MultipleSkulls,
-    TooManyFlowers,
+    /// There were more than [`MAX_HAND_SIZE`] cards
+    TooManyCards,
+    /// Cards were taken away that weren't in the hand.
+    /// Holds the hand, and the cards taken away
     RhsNotSubset(Hand, Hand),
+    /// The hand has more of a card than the game's
+    /// [starting hand](GameConfig::starting_hand), so the rules don't allow
+    /// it
+    NotAllowed(Hand),
 }
 
 impl fmt::Display for HandError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use HandError::*;
         match self {
-            MultipleSkulls => write!(f, "Invalid hand, multiple skulls"),
-            TooManyFlowers => write!(f, "Invalid hand, too many flowers"),
+            TooManyCards => write!(f, "Invalid hand, too many cards"),
             RhsNotSubset(a, b) => write!(f, "RHS of subtraction had cards the left side didn't. Left: {}. Right: {}", a, b),
+            NotAllowed(hand) => write!(f, "Hand {} has cards the starting hand doesn't", hand),
         }
     }
 }
@@ -0,0 +1,34 @@
+use crate::*;
+
+/// A single record in a [`Game`]'s history
+///
+/// See [`Game::history()`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistoryEntry {
+    /// The round the entry happened in, starting from 0
+    ///
+    /// A round ends when a challenge ends
+    pub round: usize,
+    /// The index of the player the entry concerns
+    ///
+    /// For a [`Response`], this is the player that gave it.
+    /// For an [`Event`], this is the player the event is about (e.g. the
+    /// highest bidder for [`BidStarted`](Event::BidStarted), or the
+    /// challenger for [`ChallengeStarted`](Event::ChallengeStarted))
+    pub player: usize,
+    /// What happened
+    pub item: HistoryItem,
+}
+
+/// What a [`HistoryEntry`] records
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HistoryItem {
+    /// A [`Response`] that was accepted by [`Game::respond()`]
+    Response(Response),
+    /// A notification [`Event`] that was emitted by [`Game::what_next()`]
+    ///
+    /// [`Input`](Event::Input) events are never recorded
+    Event(Event),
+}
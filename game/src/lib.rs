@@ -25,8 +25,9 @@
 //! # What does this crate provide?
 //!
 //! This crate provides a **simulation** of the game Skull.
-//! It allows for the creation of a [`Game`] for 3 to 6 players (as recommended
-//! by the original) and provides all necessary means to interact with the
+//! It allows for the creation of a [`Game`] for 2 to 10 players (the original
+//! recommends 3 to 6, but two player variants and larger tables are common)
+//! and provides all necessary means to interact with the
 //! game and understand the current state of the game.
 //! It enforces all of the games rules and scoring for you, so you only need
 //! to focus on how you wish to present the game.
@@ -36,11 +37,44 @@
 //! If you don't know, it is highly recommended to read the manual and play the
 //! game at least once to grasp it.
 //!
+//! # Serialization
+//!
+//! Enabling the `serde` feature implements `Serialize` and `Deserialize` for
+//! all of the crate's public types, as well as [`Game`].
+//! The representation is serde's default for each type, with the exceptions
+//! that:
+//! - Per-player arrays (e.g. [`Game::scores()`], or
+//!   [`State::Bidding::passed`](State::Bidding)) are sequences with one
+//!   element per player
+//! - [`FVec`]s are sequences
+//! - A [`Hand`] is the sequence of cards in it, as given by
+//!   [`Hand::as_vec()`], e.g. `["Flower", "Flower", "Skull"]`
+//!
+//! Enums are externally tagged, so in JSON [`Response::Flip(1, 2)`](Response)
+//! is `{"Flip": [1, 2]}` and [`Response::Pass`] is `"Pass"`.
+//!
+//! [`EngineError`], [`InvariantViolation`], [`HandError`], [`TableError`] and
+//! [`Named`] aren't serializable, and neither is [`ResponseError::Engine`].
+//!
+//! Deserializing a [`Game`] performs no validation of the game's state.
+//! For that, use [`Game::check_invariants()`], or enable the `save` feature
+//! and use `Game::save_to()` and `Game::load_from()`
+//!
 
 #![deny(missing_docs)]
 
+mod builder;
+mod config;
+mod dyn_game;
 mod game;
 mod hand;
+mod history;
+mod replay;
+mod rng;
+#[cfg(feature = "save")]
+mod save;
+mod table;
+mod view;
 
 /// Re-exports [heapless'](https://lib.rs/crates/heapless)
 /// [`Vec`](https://docs.rs/heapless/0.7.7/heapless/struct.Vec.html) under a
@@ -50,16 +84,43 @@ pub use heapless::Vec as FVec; // Fixed Vec
 use std::convert::TryFrom;
 use std::fmt;
 
+use crate::rng::GameRng;
+
 use Card::*;
 use Event::*;
 use State::*;
 
+#[doc(inline)]
+pub use crate::builder::GameBuilder;
+#[doc(inline)]
+pub use crate::config::GameConfig;
+#[doc(inline)]
+pub use crate::dyn_game::DynGame;
 #[doc(inline)]
 pub use crate::game::Game;
 #[doc(inline)]
-pub use crate::hand::Hand;
+pub use crate::hand::{Hand, HandError};
+#[doc(inline)]
+pub use crate::history::{HistoryEntry, HistoryItem};
+#[doc(inline)]
+pub use crate::replay::{Replay, ReplayError, REPLAY_VERSION};
+#[cfg(feature = "save")]
+#[doc(inline)]
+pub use crate::save::{SaveError, SAVE_VERSION};
+#[doc(inline)]
+pub use crate::table::{Named, Player, PlayerId, Table, TableError};
+#[doc(inline)]
+pub use crate::view::PlayerView;
+
+type OrderedHand = FVec<Card, MAX_HAND_SIZE>;
 
-type OrderedHand = FVec<Card, 4>;
+/// The most cards a [`Hand`] can have
+pub const MAX_HAND_SIZE: usize = 6;
+
+/// The fewest players a [`Game`] can have
+pub const MIN_PLAYERS: usize = 2;
+/// The most players a [`Game`] can have
+pub const MAX_PLAYERS: usize = 10;
 
 #[doc(hidden)]
 #[macro_export]
@@ -76,7 +137,12 @@ macro_rules! fvec {
 }
 
 /// A playing card
+///
+/// More kinds of card may be added (e.g. from expansions), so matching on a
+/// Card outside of this crate needs a wildcard arm
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Card {
     #[allow(missing_docs)]
     Flower,
@@ -84,6 +150,24 @@ pub enum Card {
     Skull,
 }
 
+impl Card {
+    /// The number of different kinds of card
+    pub const KINDS: usize = 2;
+    /// Every kind of card, in the order they're listed by [`Hand::as_vec()`]
+    pub const ALL: [Card; Card::KINDS] = [Flower, Skull];
+
+    /// Returns `true` if flipping the card loses a challenge, otherwise it
+    /// counts towards the challenger's target
+    pub const fn loses_challenge(self) -> bool {
+        matches!(self, Skull)
+    }
+
+    // The card's position in Card::ALL
+    pub(crate) const fn index(self) -> usize {
+        self as usize
+    }
+}
+
 impl fmt::Display for Card {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Reuse Debug impl
@@ -98,6 +182,10 @@ impl fmt::Display for Card {
 /// 2. Bidding (determining a number of cards to challenge for)
 /// 3. Challenging (trying to turn over the chosen number of flowers)
 ///
+/// If the challenger flips a skull, a fourth phase, Discarding (choosing a
+/// card for the challenger to lose), may follow.
+/// Once someone has won, the game stays Finished
+///
 /// This enum has a variant for each state, each of which holds any additional
 /// information relevant only to that state
 ///
@@ -107,6 +195,7 @@ impl fmt::Display for Card {
 /// [`Game::state()`], instead of creating one
 ///
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum State<const N: usize> {
     /// When players are putting down cards
     Playing {
@@ -124,6 +213,10 @@ pub enum State<const N: usize> {
         /// The highest bid possible (total number of cards played)
         max_bid: usize,
         /// Keeps track of the players who have passed
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_big_array::BigArray")
+        )]
         passed: [bool; N],
     },
     /// When a player is trying to turn over the chosen number of flowers
@@ -136,8 +229,44 @@ pub enum State<const N: usize> {
         ///
         /// For the challenger, the indexes will always be ordered from low to
         /// high as the cards are automatically flipped for them
-        flipped: [FVec<usize, 4>; N],
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_big_array::BigArray")
+        )]
+        flipped: [FVec<usize, MAX_HAND_SIZE>; N],
     },
+    /// When the challenger has flipped a skull and a card of theirs must be
+    /// chosen to be discarded
+    ///
+    /// Only reached when the game's [`DiscardMode`] is
+    /// [`Chosen`](DiscardMode::Chosen)
+    Discarding {
+        /// The index of the challenger, who is losing a card
+        challenger: usize,
+        /// The index of the player whose skull was flipped, who chooses the
+        /// card to discard
+        /// (can potentially be the same as `challenger`)
+        skull_player: usize,
+    },
+    /// When the game is over
+    ///
+    /// No more input will be accepted
+    Finished {
+        /// The index of the player who won
+        winner: usize,
+        /// How they won
+        reason: WinReason,
+    },
+}
+
+/// How a player won the game
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WinReason {
+    /// The player won two challenges
+    ChallengesWon,
+    /// Every other player is out
+    LastPlayerStanding,
 }
 
 /// An event is either a notification of something important, or a prompt to
@@ -150,6 +279,7 @@ pub enum State<const N: usize> {
 /// See [`Game::what_next()`] for more information
 ///
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// Indicates that an input is required from one of the game's players
     Input {
@@ -181,11 +311,22 @@ pub enum Event {
     ChallengeWon(usize),
     /// Notifies that the challenger won their challenge and has now won the
     /// game (index of winner provided)
+    ///
+    /// Once this has been processed, the game is over and will not accept any
+    /// more input
     ChallengeWonGameWon(usize),
+    /// Notifies that every other player is out, so the remaining player has
+    /// won the game (index of winner provided)
+    ///
+    /// Follows the final [`Event::PlayerOut`].
+    /// Once this has been processed, the game is over and will not accept any
+    /// more input
+    LastPlayerStanding(usize),
 }
 
 /// The type of input required from the player
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InputType {
     /// The player must play a card
     PlayCard, // When not everyone has played a card
@@ -197,10 +338,13 @@ pub enum InputType {
     BidOrPass,
     /// The challenger must flip a card
     FlipCard,
+    /// The skull player must choose one of the challenger's cards to discard
+    ChooseDiscard,
 }
 
 /// The type of input given to the game using [`Game::respond()`]
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Response {
     /// The current player plays the specified card
     PlayCard(Card),
@@ -210,34 +354,123 @@ pub enum Response {
     Pass,
     /// The challenger flips over a card `(player_index, card_index)`
     Flip(usize, usize),
+    /// The skull player discards the challenger's card at the given index
+    ///
+    /// The index is into the challenger's [hand](Hand::as_vec()).
+    /// If the skull player isn't the challenger, the challenger's cards are
+    /// shuffled first, as the skull player must choose blindly
+    Discard(usize),
+}
+
+/// How the card a challenger loses after flipping a skull is decided
+///
+/// Defaults to [`Chosen`](DiscardMode::Chosen)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiscardMode {
+    /// As in the rules, the owner of the skull chooses (blindly, unless it's
+    /// the challenger's own skull) using [`Response::Discard`]
+    #[default]
+    Chosen,
+    /// A card is chosen at random as soon as the skull is flipped, skipping
+    /// [`State::Discarding`]
+    Random,
+}
+
+/// How the player who starts the game is chosen
+///
+/// Defaults to the player in seat 0
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FirstPlayer {
+    /// The player in the given seat starts
+    Seat(usize),
+    /// A player is chosen using the game's random number generator, so a
+    /// seeded game always starts with the same player
+    Random,
+}
+
+impl Default for FirstPlayer {
+    fn default() -> Self {
+        FirstPlayer::Seat(0)
+    }
 }
 
 /// The type of error produced by [`Game::respond()`]
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ResponseError {
     /// Can't take an input now because there is another [`Event`] that needs
     /// processing.
     /// Call [`Game::what_next()`]
     PendingEvent,
-    /// Input type didn't match what was expected.
-    /// Correct [`InputType`] provided
-    IncorrectInputType(InputType),
+    /// Input type didn't match what was expected
+    IncorrectInputType {
+        /// The type of input that was expected
+        expected: InputType,
+        /// The response that was given
+        response: Response,
+    },
     /// Player tried to put down a card they don't have
     /// (either already played, or discarded)
-    CardNotInHand,
-    /// Bid submitted was lower than current bid.
-    /// Minimum acceptable bid provided
-    BidTooLow(usize),
-    /// Bid submitted was too high (in excess of the number of cards played).
-    /// Maximum acceptable bid provided
-    BidTooHigh(usize),
-    /// Out of range index given when challenger tried to flip a card
-    InvalidIndex,
+    CardNotInHand {
+        /// The index of the player trying to play the card
+        player: usize,
+        /// The card they tried to play
+        card: Card,
+    },
+    /// Bid submitted was lower than current bid
+    BidTooLow {
+        /// The bid that was submitted
+        bid: usize,
+        /// The minimum acceptable bid
+        min: usize,
+    },
+    /// Bid submitted was too high (in excess of the number of cards played)
+    BidTooHigh {
+        /// The bid that was submitted
+        bid: usize,
+        /// The maximum acceptable bid
+        max: usize,
+    },
+    /// Challenger tried to flip a card of a player that doesn't exist
+    InvalidPlayerIndex {
+        /// The player index given
+        player: usize,
+        /// The number of players in the game
+        player_count: usize,
+    },
+    /// Out of range index given when challenger tried to flip a card, or
+    /// when choosing a card to discard
+    InvalidIndex {
+        /// The index of the player whose cards were being chosen from
+        /// (the challenger, when discarding)
+        player: usize,
+        /// The index given
+        index: usize,
+        /// The number of cards there were to choose from
+        len: usize,
+    },
     /// Challenger is trying to flip a card they've already flipped
-    CardAlreadyFlipped,
+    CardAlreadyFlipped {
+        /// The index of the player the card belongs to
+        player: usize,
+        /// The index of the card
+        index: usize,
+    },
     /// Challenger is trying to flip their own cards
     /// (they're flipped automatically)
-    ManuallyFlippingOwnCards,
+    ManuallyFlippingOwnCards {
+        /// The index of the challenger
+        player: usize,
+        /// The index of the card
+        index: usize,
+    },
+    /// The game is over, so no more input is accepted
+    GameOver,
+    /// The game is in an inconsistent state, so no more input is accepted
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Engine(EngineError),
 }
 
 impl fmt::Display for ResponseError {
@@ -246,23 +479,123 @@ impl fmt::Display for ResponseError {
         use ResponseError::*;
         match self {
             PendingEvent => write!(f, "There's a pending event that needs to be processed using Game.what_next()"),
-            IncorrectInputType(it) => {
-                write!(f, "Incorrect input type, expected {}", match it {
+            IncorrectInputType { expected, response } => {
+                write!(f, "Incorrect input type, expected {} but got {:?}", match expected {
                     PlayCard => "PlayCard",
                     PlayCardOrStartBid => "PlayCard or Bid",
                     StartBid => "Bid",
                     BidOrPass => "Bid or Pass",
                     FlipCard => "Flip",
-                })
+                    ChooseDiscard => "Discard",
+                }, response)
+            }
+            CardNotInHand { player, card } => write!(f, "Player {} doesn't have a {} to play", player, card),
+            BidTooLow { bid, min } => write!(f, "Bid of {} too low, needs to be at least {}", bid, min),
+            BidTooHigh { bid, max } => write!(f, "Bid of {} too high, needs to be at most {}", bid, max),
+            InvalidPlayerIndex { player, player_count } => write!(f, "Invalid player {}, there are only {} players", player, player_count),
+            InvalidIndex { player, index, len } => write!(f, "Invalid index {} for player {}'s cards, needs to be less than {}", index, player, len),
+            CardAlreadyFlipped { player, index } => write!(f, "Player {}'s card at index {} has already been flipped", player, index),
+            ManuallyFlippingOwnCards { index, .. } => write!(f, "Challenger is trying to flip their own card at index {}, which is flipped automatically", index),
+            GameOver => write!(f, "The game is over"),
+            Engine(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ResponseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ResponseError::Engine(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<EngineError> for ResponseError {
+    fn from(err: EngineError) -> Self {
+        ResponseError::Engine(err)
+    }
+}
+
+/// The type of error produced when a [`Game`] is found to be in an
+/// inconsistent state
+///
+/// This should never happen to a game played through [`Game::respond()`] and
+/// [`Game::what_next()`].
+/// It can happen to a game built from outside the engine (e.g. deserialized),
+/// which can be checked up front with [`Game::check_invariants()`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EngineError {
+    /// One of the game's invariants doesn't hold, with a description of it
+    InvalidState(&'static str),
+    /// Every player is out, so there's no one left to play
+    AllPlayersOut,
+}
+
+impl fmt::Display for EngineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use EngineError::*;
+        match self {
+            InvalidState(reason) => {
+                write!(f, "The game is in an invalid state: {}", reason)
+            }
+            AllPlayersOut => write!(f, "All players are out"),
+        }
+    }
+}
+
+impl std::error::Error for EngineError {}
+
+/// An invariant of a [`Game`] that doesn't hold, with a description of it
+///
+/// See [`Game::validate_state()`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InvariantViolation {
+    /// The rules can't be played with, or not by this many players
+    Config(&'static str),
+    /// The scores are inconsistent with each other or the rest of the game
+    Scores(&'static str),
+    /// A player's hand is invalid
+    Hands(&'static str),
+    /// The cards on the table couldn't have been played
+    CardsPlayed(&'static str),
+    /// The pending event doesn't match the rest of the game
+    PendingEvent(&'static str),
+    /// The [`State`] is invalid
+    State(&'static str),
+}
+
+impl InvariantViolation {
+    /// Gets the description of the invariant that doesn't hold
+    pub const fn description(&self) -> &'static str {
+        use InvariantViolation::*;
+        match self {
+            Config(description)
+            | Scores(description)
+            | Hands(description)
+            | CardsPlayed(description)
+            | PendingEvent(description)
+            | State(description) => description,
+        }
+    }
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use InvariantViolation::*;
+        match self {
+            Config(description) => write!(f, "Invalid rules: {}", description),
+            Scores(description) => write!(f, "Invalid scores: {}", description),
+            Hands(description) => write!(f, "Invalid hands: {}", description),
+            CardsPlayed(description) => {
+                write!(f, "Invalid cards played: {}", description)
+            }
+            PendingEvent(description) => {
+                write!(f, "Invalid pending event: {}", description)
             }
-            CardNotInHand => write!(f, "The player doesn't have that card"),
-            BidTooLow(min) => write!(f, "Bid too low, needs to be at least {}", min),
-            BidTooHigh(max) => write!(f, "Bid too high, needs to be at most {}", max),
-            InvalidIndex => write!(f, "Invalid index, outside of allowed range"),
-            CardAlreadyFlipped => write!(f, "The player has already flipped that card"),
-            ManuallyFlippingOwnCards => write!(f, "Challenger is trying to flip their own cards, which are already flipped"),
+            State(description) => write!(f, "Invalid state: {}", description),
         }
     }
 }
 
-impl std::error::Error for ResponseError {}
+impl std::error::Error for InvariantViolation {}
@@ -0,0 +1,509 @@
+use crate::*;
+
+use std::error::Error;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+
+/// The current version of the replay file format
+pub const REPLAY_VERSION: u32 = 1;
+
+const MAGIC: &str = "skull-replay";
+
+/// A recording of a complete game, which can be stepped through
+///
+/// A replay only stores what is needed to recreate the game: the number of
+/// players, the seed, the [`GameConfig`] and every accepted [`Response`].
+/// The game is re-run through [`Game::respond()`] and [`Game::what_next()`]
+/// to reproduce every [`Event`].
+/// Replays of a [`Table`] also store the ID of the player in each seat
+///
+/// Replay is generic over the number of players
+#[derive(Debug, Clone)]
+pub struct Replay<const N: usize> {
+    seed: u64,
+    config: GameConfig,
+    responses: Vec<Response>,
+    // The ID of the player in each seat
+    seating: Vec<PlayerId>,
+    position: usize,
+    game: Game<N>,
+}
+
+impl<const N: usize> Replay<N> {
+    /// Creates a replay of a game from its starting information and the
+    /// responses given to it, positioned at the start of the game
+    ///
+    /// Every response is checked by re-running the game, and an error is
+    /// returned if any of them would not have been accepted
+    ///
+    /// Panics if the rules can't be played with, as in
+    /// [`Game::with_config()`]
+    pub fn new(
+        seed: u64,
+        config: GameConfig,
+        responses: Vec<Response>,
+    ) -> Result<Self, ReplayError> {
+        let mut replay = Replay {
+            seed,
+            config,
+            responses,
+            seating: (0..N).map(|seat| PlayerId(seat as u64)).collect(),
+            position: 0,
+            game: Self::start(seed, config),
+        };
+        // Check the whole replay is valid
+        while replay.try_step_forward()?.is_some() {}
+        replay.jump_to(0);
+        Ok(replay)
+    }
+
+    /// Creates a replay of the given game, positioned at the start of the
+    /// game
+    ///
+    /// Returns `None` if the game can't be replayed
+    /// (see [`Game::is_replayable()`])
+    pub fn from_game(game: &Game<N>) -> Option<Self> {
+        if !game.is_replayable() {
+            return None;
+        }
+        let responses = game
+            .history()
+            .iter()
+            .filter_map(|entry| match entry.item {
+                HistoryItem::Response(response) => Some(response),
+                HistoryItem::Event(_) => None,
+            })
+            .collect();
+        Replay::new(game.seed(), *game.config(), responses).ok()
+    }
+
+    /// Creates a replay of the game being played at the given table,
+    /// including who sat where, positioned at the start of the game
+    ///
+    /// Returns `None` if the game can't be replayed
+    /// (see [`Game::is_replayable()`])
+    pub fn from_table(table: &Table<N>) -> Option<Self> {
+        let mut replay = Replay::from_game(table.game())?;
+        replay.seating = table.players().iter().map(Player::id).collect();
+        Some(replay)
+    }
+
+    fn start(seed: u64, config: GameConfig) -> Game<N> {
+        let mut game = Game::with_config(config);
+        game.reseed(seed);
+        game
+    }
+
+    /// Gets the game as it is at the current position in the replay
+    pub const fn game(&self) -> &Game<N> {
+        &self.game
+    }
+
+    /// Gets all of the responses in the replay
+    pub fn responses(&self) -> &[Response] {
+        &self.responses
+    }
+
+    /// Gets the ID of the player in each seat, in seat order
+    ///
+    /// Unless the replay was made with [`from_table()`](Replay::from_table()),
+    /// each player's ID is their seat
+    pub fn seating(&self) -> &[PlayerId] {
+        &self.seating
+    }
+
+    /// Gets the number of responses that have been replayed so far
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Gets the total number of responses in the replay
+    pub fn len(&self) -> usize {
+        self.responses.len()
+    }
+
+    /// Returns `true` if the replay has no responses in it
+    pub fn is_empty(&self) -> bool {
+        self.responses.is_empty()
+    }
+
+    /// Returns `true` if every response in the replay has been replayed
+    pub fn at_end(&self) -> bool {
+        self.position == self.responses.len()
+    }
+
+    /// Replays the next response, returning the notification [`Event`]s it
+    /// caused
+    ///
+    /// Returns `None` if at the end of the replay
+    pub fn step_forward(&mut self) -> Option<Vec<Event>> {
+        self.try_step_forward().ok().flatten()
+    }
+
+    fn try_step_forward(&mut self) -> Result<Option<Vec<Event>>, ReplayError> {
+        let response = match self.responses.get(self.position) {
+            Some(response) => *response,
+            None => return Ok(None),
+        };
+        let position = self.position;
+        let invalid = |error: ResponseError| ReplayError::InvalidResponse {
+            position,
+            response,
+            error,
+        };
+        self.game.respond(response).map_err(invalid)?;
+        self.position += 1;
+
+        // Process events until the game is waiting on the next response
+        let mut events = Vec::new();
+        loop {
+            match self
+                .game
+                .what_next()
+                .map_err(|err| invalid(ResponseError::from(err)))?
+            {
+                Event::Input { .. } => break,
+                event => {
+                    events.push(event);
+                    if let State::Finished { .. } = self.game.state() {
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(Some(events))
+    }
+
+    /// Undoes the last replayed response
+    ///
+    /// Returns `false` if at the start of the replay
+    pub fn step_back(&mut self) -> bool {
+        if self.position == 0 {
+            false
+        } else {
+            self.jump_to(self.position - 1);
+            true
+        }
+    }
+
+    /// Moves to the given position in the replay (the number of responses
+    /// that have been replayed)
+    ///
+    /// Positions past the end of the replay move to the end
+    pub fn jump_to(&mut self, position: usize) {
+        if position < self.position {
+            self.game = Self::start(self.seed, self.config);
+            self.position = 0;
+        }
+        while self.position < position && self.step_forward().is_some() {}
+    }
+
+    /// Moves to the start of the given round (the position of its first
+    /// response)
+    ///
+    /// Returns `false` and moves to the end of the replay if the round is
+    /// never reached
+    pub fn jump_to_round(&mut self, round: usize) -> bool {
+        self.jump_to(0);
+        while self.game.round() < round {
+            if self.step_forward().is_none() {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Writes the replay to `writer` in the replay file format
+    ///
+    /// The format is plain text.
+    /// The first nine lines are a header, giving the format version, the
+    /// number of players, the seed, then the rules (see [`GameConfig`]):
+    /// the discard mode, the points to win, the starting hand, whether the
+    /// challenger flips their own cards first, and the first player (a seat,
+    /// or `random`), and finally the ID of the player in each seat
+    /// (see [`seating()`](Replay::seating())).
+    /// Then all the responses follow on one line, separated by spaces:
+    /// - `F` and `S` play a flower and skull respectively
+    /// - `B3` bids 3
+    /// - `P` passes
+    /// - `X1.2` flips player 1's card at index 2
+    /// - `D0` discards the challenger's card at index 0
+    ///
+    /// For example:
+    ///
+    /// ```text
+    /// skull-replay 1
+    /// players 3
+    /// seed 42
+    /// discard chosen
+    /// points-to-win 2
+    /// starting-hand FFFS
+    /// flip-own-first true
+    /// first-player 0
+    /// seating 2 0 1
+    /// F F S B2 P P X1.0
+    /// ```
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "{} {}", MAGIC, REPLAY_VERSION)?;
+        writeln!(writer, "players {}", N)?;
+        writeln!(writer, "seed {}", self.seed)?;
+        writeln!(
+            writer,
+            "discard {}",
+            match self.config.discard_mode {
+                DiscardMode::Chosen => "chosen",
+                DiscardMode::Random => "random",
+            }
+        )?;
+        writeln!(writer, "points-to-win {}", self.config.points_to_win)?;
+        let starting_hand = self
+            .config
+            .starting_hand
+            .as_vec()
+            .iter()
+            .map(|card| encode_response(Response::PlayCard(*card)))
+            .collect::<String>();
+        writeln!(writer, "starting-hand {}", starting_hand)?;
+        writeln!(
+            writer,
+            "flip-own-first {}",
+            self.config.challenger_flips_own_first
+        )?;
+        match self.config.first_player {
+            FirstPlayer::Seat(seat) => {
+                writeln!(writer, "first-player {}", seat)?
+            }
+            FirstPlayer::Random => writeln!(writer, "first-player random")?,
+        }
+        let seating = self
+            .seating
+            .iter()
+            .map(|id| id.0.to_string())
+            .collect::<Vec<_>>();
+        writeln!(writer, "seating {}", seating.join(" "))?;
+        let responses = self
+            .responses
+            .iter()
+            .map(|response| encode_response(*response))
+            .collect::<Vec<_>>();
+        writeln!(writer, "{}", responses.join(" "))
+    }
+
+    /// Reads a replay previously written by
+    /// [`write_to()`](Replay::write_to())
+    ///
+    /// The replay is checked as in [`Replay::new()`]
+    pub fn read_from<R: Read>(reader: R) -> Result<Self, ReplayError> {
+        use ReplayError::*;
+        let mut lines = BufReader::new(reader).lines();
+        let mut header = |key: &'static str| -> Result<String, ReplayError> {
+            let line = lines.next().ok_or(MissingHeader(key))??;
+            match line.split_once(' ') {
+                Some((k, value)) if k == key => Ok(value.to_owned()),
+                _ => Err(MissingHeader(key)),
+            }
+        };
+
+        let version = header(MAGIC)?;
+        let version =
+            u32::from_str(&version).map_err(|_| MissingHeader(MAGIC))?;
+        if version != REPLAY_VERSION {
+            return Err(UnsupportedVersion(version));
+        }
+        let players = header("players")?;
+        match usize::from_str(&players) {
+            Ok(players) if players == N => {}
+            Ok(players) => {
+                return Err(WrongPlayerCount {
+                    expected: N,
+                    found: players,
+                })
+            }
+            Err(_) => return Err(MissingHeader("players")),
+        }
+        let seed = u64::from_str(&header("seed")?)
+            .map_err(|_| MissingHeader("seed"))?;
+        let discard_mode = match header("discard")?.as_str() {
+            "chosen" => DiscardMode::Chosen,
+            "random" => DiscardMode::Random,
+            _ => return Err(MissingHeader("discard")),
+        };
+        let points_to_win = u8::from_str(&header("points-to-win")?)
+            .map_err(|_| MissingHeader("points-to-win"))?;
+        let starting_hand = header("starting-hand")?
+            .chars()
+            .map(|c| match decode_response(&c.to_string()) {
+                Some(Response::PlayCard(card)) => Some(card),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or(MissingHeader("starting-hand"))?;
+        let starting_hand = Hand::try_from(starting_hand.as_slice())
+            .map_err(|_| MissingHeader("starting-hand"))?;
+        let challenger_flips_own_first =
+            bool::from_str(&header("flip-own-first")?)
+                .map_err(|_| MissingHeader("flip-own-first"))?;
+        let first_player = match header("first-player")?.as_str() {
+            "random" => FirstPlayer::Random,
+            seat => FirstPlayer::Seat(
+                usize::from_str(seat)
+                    .map_err(|_| MissingHeader("first-player"))?,
+            ),
+        };
+        let config = GameConfig {
+            points_to_win,
+            starting_hand,
+            challenger_flips_own_first,
+            discard_mode,
+            first_player,
+        };
+        let seating = header("seating")?
+            .split_whitespace()
+            .map(|id| u64::from_str(id).map(PlayerId))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| MissingHeader("seating"))?;
+        let unique = seating
+            .iter()
+            .enumerate()
+            .all(|(seat, id)| !seating[..seat].contains(id));
+        if seating.len() != N || !unique {
+            return Err(MissingHeader("seating"));
+        }
+        config.check_valid(N).map_err(InvalidConfig)?;
+
+        let mut responses = Vec::new();
+        for line in lines {
+            for token in line?.split_whitespace() {
+                let response = decode_response(token)
+                    .ok_or_else(|| InvalidToken(token.to_owned()))?;
+                responses.push(response);
+            }
+        }
+        let mut replay = Replay::new(seed, config, responses)?;
+        replay.seating = seating;
+        Ok(replay)
+    }
+}
+
+fn encode_response(response: Response) -> String {
+    use Response::*;
+    match response {
+        PlayCard(Card::Flower) => "F".to_owned(),
+        PlayCard(Card::Skull) => "S".to_owned(),
+        Bid(n) => format!("B{}", n),
+        Pass => "P".to_owned(),
+        Flip(player_index, card_index) => {
+            format!("X{}.{}", player_index, card_index)
+        }
+        Discard(card_index) => format!("D{}", card_index),
+    }
+}
+
+fn decode_response(token: &str) -> Option<Response> {
+    use Response::*;
+    if token.is_empty() || !token.is_char_boundary(1) {
+        return None;
+    }
+    let (kind, rest) = token.split_at(1);
+    match (kind, rest) {
+        ("F", "") => Some(PlayCard(Card::Flower)),
+        ("S", "") => Some(PlayCard(Card::Skull)),
+        ("P", "") => Some(Pass),
+        ("B", n) => n.parse().ok().map(Bid),
+        ("D", n) => n.parse().ok().map(Discard),
+        ("X", indexes) => {
+            let (player_index, card_index) = indexes.split_once('.')?;
+            Some(Flip(player_index.parse().ok()?, card_index.parse().ok()?))
+        }
+        _ => None,
+    }
+}
+
+/// The type of error produced when creating or reading a [`Replay`]
+#[derive(Debug)]
+pub enum ReplayError {
+    /// Reading the replay file failed
+    Io(io::Error),
+    /// The replay file is missing the given header, or its value is invalid
+    MissingHeader(&'static str),
+    /// The replay file was written using a different version of the replay
+    /// file format.
+    /// Version of the replay file provided
+    UnsupportedVersion(u32),
+    /// The replay is for a game with a different number of players
+    WrongPlayerCount {
+        /// The number of players in the game being replayed
+        expected: usize,
+        /// The number of players in the replay file
+        found: usize,
+    },
+    /// The replay file contains something that isn't a response.
+    /// Offending text provided
+    InvalidToken(String),
+    /// The replay file's rules can't be played with.
+    /// Reason provided
+    InvalidConfig(&'static str),
+    /// The game didn't accept one of the responses in the replay
+    InvalidResponse {
+        /// The position of the response in the replay
+        position: usize,
+        /// The response that wasn't accepted
+        response: Response,
+        /// Why it wasn't accepted
+        error: ResponseError,
+    },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ReplayError::*;
+        match self {
+            Io(err) => write!(f, "Couldn't read replay file: {}", err),
+            MissingHeader(key) => {
+                write!(f, "Invalid replay file, missing or invalid {}", key)
+            }
+            UnsupportedVersion(version) => write!(
+                f,
+                "Unsupported replay file version {}, expected {}",
+                version, REPLAY_VERSION
+            ),
+            WrongPlayerCount { expected, found } => write!(
+                f,
+                "Replay is for {} players, expected {}",
+                found, expected
+            ),
+            InvalidConfig(reason) => {
+                write!(f, "Invalid replay file, bad rules: {}", reason)
+            }
+            InvalidToken(token) => {
+                write!(f, "Invalid replay file, {:?} isn't a response", token)
+            }
+            InvalidResponse {
+                position,
+                response,
+                error,
+            } => write!(
+                f,
+                "Response {} ({:?}) in replay wasn't accepted: {}",
+                position, response, error
+            ),
+        }
+    }
+}
+
+impl Error for ReplayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use ReplayError::*;
+        match self {
+            Io(err) => Some(err),
+            InvalidResponse { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReplayError {
+    fn from(err: io::Error) -> Self {
+        ReplayError::Io(err)
+    }
+}
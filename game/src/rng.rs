@@ -0,0 +1,41 @@
+use nanorand::{Rng, WyRand};
+use std::ops::Range;
+
+/// The random number generator owned by a [`Game`](crate::Game)
+///
+/// Wraps [`WyRand`], reseeding it after every use so that the entire state of
+/// the generator is a single `u64` that we can get at (`WyRand` keeps its
+/// state private)
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub(crate) struct GameRng {
+    state: u64,
+}
+
+impl GameRng {
+    pub(crate) const fn new(seed: u64) -> Self {
+        GameRng { state: seed }
+    }
+
+    /// Creates a new seed from the system's source of entropy
+    pub(crate) fn entropy_seed() -> u64 {
+        WyRand::new().generate()
+    }
+
+    /// Generates a number in the given range (`start..end`)
+    pub(crate) fn generate_range(&mut self, range: Range<usize>) -> usize {
+        let mut rng = WyRand::new_seed(self.state);
+        let n = rng.generate_range(range);
+        self.state = rng.generate();
+        n
+    }
+
+    /// Shuffles the given slice in place
+    pub(crate) fn shuffle<T>(&mut self, slice: &mut [T]) {
+        // Fisher-Yates
+        for i in (1..slice.len()).rev() {
+            slice.swap(i, self.generate_range(0..i + 1));
+        }
+    }
+}
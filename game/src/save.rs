@@ -0,0 +1,203 @@
+use crate::table::UncheckedTable;
+use crate::*;
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::io::{self, Read, Write};
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// The current version of the save file format
+///
+/// Incremented whenever a change is made that means older versions of the
+/// crate can't read new save files, or vice versa
+pub const SAVE_VERSION: u64 = 1;
+
+#[derive(Serialize)]
+struct SaveFile<'a, const N: usize> {
+    version: u64,
+    players: usize,
+    game: &'a Game<N>,
+    // Only for a Table
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seats: Option<&'a [Player]>,
+}
+
+impl<const N: usize> Game<N> {
+    /// Writes the game to `writer` as a save file
+    ///
+    /// A save file is a JSON object with three fields:
+    /// - `version`: the save file format version ([`SAVE_VERSION`])
+    /// - `players`: the number of players
+    /// - `game`: the game itself, as described in the
+    ///   [crate documentation](crate#serialization)
+    ///
+    /// Everything is saved, including any pending event, the game's history,
+    /// and the state of its random number generator
+    pub fn save_to<W: Write>(&self, writer: W) -> Result<(), SaveError> {
+        let file = SaveFile {
+            version: SAVE_VERSION,
+            players: N,
+            game: self,
+            seats: None,
+        };
+        serde_json::to_writer(writer, &file)?;
+        Ok(())
+    }
+
+    /// Reads a game from a save file previously written by
+    /// [`save_to()`](Game::save_to())
+    ///
+    /// The loaded game is checked with the same rules as
+    /// [`GameBuilder::build()`], and an error is returned
+    /// instead of a game that is in any way invalid
+    pub fn load_from<R: Read>(reader: R) -> Result<Self, SaveError> {
+        let mut file: Value = serde_json::from_reader(reader)?;
+        Game::from_save_file(&mut file)
+    }
+
+    // Takes the game out of a save file, checking it
+    fn from_save_file(file: &mut Value) -> Result<Self, SaveError> {
+        use SaveError::*;
+        match file.get("version").and_then(Value::as_u64) {
+            Some(SAVE_VERSION) => {}
+            Some(version) => return Err(UnsupportedVersion(version)),
+            None => return Err(MissingField("version")),
+        }
+        match file.get("players").and_then(Value::as_u64) {
+            Some(players) if players as usize == N => {}
+            Some(players) => {
+                return Err(WrongPlayerCount {
+                    expected: N,
+                    found: players as usize,
+                })
+            }
+            None => return Err(MissingField("players")),
+        }
+        let game = file.get_mut("game").ok_or(MissingField("game"))?.take();
+
+        let game: Game<N> = serde_json::from_value(game)?;
+        game.check_valid().map_err(InvalidGame)?;
+        Ok(game)
+    }
+}
+
+impl<const N: usize> Table<N> {
+    /// Writes the table to `writer` as a save file
+    ///
+    /// This is a game's save file (see [`Game::save_to()`]) with a fourth
+    /// field, `seats`: the players in seat order, so it can also be loaded
+    /// as just the game
+    pub fn save_to<W: Write>(&self, writer: W) -> Result<(), SaveError> {
+        let file = SaveFile {
+            version: SAVE_VERSION,
+            players: N,
+            game: self.game(),
+            seats: Some(self.players()),
+        };
+        serde_json::to_writer(writer, &file)?;
+        Ok(())
+    }
+
+    /// Reads a table from a save file previously written by
+    /// [`save_to()`](Table::save_to())
+    ///
+    /// The game is checked as in [`Game::load_from()`], and the players as
+    /// when a Table is deserialized
+    pub fn load_from<R: Read>(reader: R) -> Result<Self, SaveError> {
+        let mut file: Value = serde_json::from_reader(reader)?;
+        let game = Game::from_save_file(&mut file)?;
+        let seats = file
+            .get_mut("seats")
+            .ok_or(SaveError::MissingField("seats"))?
+            .take();
+        let players = serde_json::from_value(seats)?;
+        Table::try_from(UncheckedTable { game, players })
+            .map_err(SaveError::InvalidPlayers)
+    }
+}
+
+/// The type of error produced when saving or loading a [`Game`] or [`Table`]
+#[derive(Debug)]
+pub enum SaveError {
+    /// Reading or writing the save file failed
+    Io(io::Error),
+    /// The save file isn't valid JSON, or doesn't describe a game
+    Format(serde_json::Error),
+    /// The save file is missing the given field
+    MissingField(&'static str),
+    /// The save file was written using a different version of the save file
+    /// format.
+    /// Version of the save file provided
+    UnsupportedVersion(u64),
+    /// The save file is for a game with a different number of players
+    WrongPlayerCount {
+        /// The number of players in the game being loaded
+        expected: usize,
+        /// The number of players in the save file
+        found: usize,
+    },
+    /// The game in the save file breaks the rules of the game.
+    /// Reason provided
+    InvalidGame(&'static str),
+    /// The players in the save file can't be seated at the game
+    InvalidPlayers(TableError),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use SaveError::*;
+        match self {
+            Io(err) => write!(f, "Couldn't access save file: {}", err),
+            Format(err) => write!(f, "Invalid save file: {}", err),
+            MissingField(field) => {
+                write!(f, "Invalid save file, missing {}", field)
+            }
+            UnsupportedVersion(version) => write!(
+                f,
+                "Unsupported save file version {}, expected {}",
+                version, SAVE_VERSION
+            ),
+            WrongPlayerCount { expected, found } => write!(
+                f,
+                "Save file is for {} players, expected {}",
+                found, expected
+            ),
+            InvalidGame(reason) => {
+                write!(f, "Save file contains an invalid game: {}", reason)
+            }
+            InvalidPlayers(err) => {
+                write!(f, "Save file contains invalid players: {}", err)
+            }
+        }
+    }
+}
+
+impl Error for SaveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use SaveError::*;
+        match self {
+            Io(err) => Some(err),
+            Format(err) => Some(err),
+            InvalidPlayers(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SaveError {
+    fn from(err: io::Error) -> Self {
+        SaveError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(err: serde_json::Error) -> Self {
+        if err.is_io() {
+            SaveError::Io(err.into())
+        } else {
+            SaveError::Format(err)
+        }
+    }
+}
@@ -0,0 +1,451 @@
+use crate::*;
+
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+// Mixed into the game's seed to seat players, so that seating them doesn't
+// use up the game's own random numbers, which would stop it being replayable
+const SEATING_SALT: u64 = 0x5ea7_5ea7_5ea7_5ea7;
+
+/// A stable identifier for a player
+///
+/// Unlike a player's seat (their index in the [`Game`]), IDs are chosen by
+/// you, so can be used to recognise the same player across games
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerId(pub u64);
+
+/// Who is sitting in one of a [`Table`]'s seats
+///
+/// It is expected that you would only ever get a Player from a [`Table`],
+/// instead of creating one
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Player {
+    id: PlayerId,
+    name: String,
+    seat: usize,
+}
+
+impl Player {
+    /// Gets the player's ID
+    pub const fn id(&self) -> PlayerId {
+        self.id
+    }
+
+    /// Gets the player's display name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the player's seat, which is their index in the [`Game`]
+    pub const fn seat(&self) -> usize {
+        self.seat
+    }
+}
+
+/// A [`Game`] along with the [`Player`] sitting in each of its seats
+///
+/// Everything in a `Game` refers to players by their seat, so a `Table` keeps
+/// the players alongside the game, and can describe what's happening using
+/// their names (see [`Table::named()`])
+///
+/// Table is generic over the number of players
+///
+/// ```
+/// use game::*;
+///
+/// let mut table = Table::with_names(
+///     Game::<3>::with_seed(1),
+///     ["Alice", "Bob", "Carol"],
+/// )
+/// .unwrap();
+/// let event = table.what_next().unwrap();
+/// assert_eq!(table.named(event).to_string(), "Waiting on Alice");
+/// ```
+///
+/// A deserialized Table's players are checked the same way as by
+/// [`Table::new()`], and each must be in the seat matching their place in the
+/// list of players
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "UncheckedTable<N>"))]
+pub struct Table<const N: usize> {
+    game: Game<N>,
+    // Always one per seat, in seat order
+    players: Vec<Player>,
+}
+
+// What a Table is deserialized as, before the players are checked
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+pub(crate) struct UncheckedTable<const N: usize> {
+    pub(crate) game: Game<N>,
+    pub(crate) players: Vec<Player>,
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> TryFrom<UncheckedTable<N>> for Table<N> {
+    type Error = TableError;
+
+    fn try_from(table: UncheckedTable<N>) -> Result<Self, Self::Error> {
+        let misplaced = table
+            .players
+            .iter()
+            .enumerate()
+            .find(|(seat, player)| player.seat != *seat);
+        if let Some((_, player)) = misplaced {
+            return Err(TableError::WrongSeat(player.id));
+        }
+        let players = table
+            .players
+            .into_iter()
+            .map(|player| (player.id, player.name));
+        Table::new(table.game, players)
+    }
+}
+
+impl<const N: usize> Table<N> {
+    /// Seats players at a game in the order given, as `(id, name)` pairs
+    ///
+    /// Returns an error unless exactly one player is given for each seat,
+    /// with no two players sharing an ID
+    pub fn new<I, S>(game: Game<N>, players: I) -> Result<Self, TableError>
+    where
+        I: IntoIterator<Item = (PlayerId, S)>,
+        S: Into<String>,
+    {
+        let players = players
+            .into_iter()
+            .enumerate()
+            .map(|(seat, (id, name))| Player {
+                id,
+                name: name.into(),
+                seat,
+            })
+            .collect::<Vec<_>>();
+        if players.len() != N {
+            return Err(TableError::WrongPlayerCount {
+                expected: N,
+                found: players.len(),
+            });
+        }
+        for (seat, player) in players.iter().enumerate() {
+            if players[..seat].iter().any(|other| other.id == player.id) {
+                return Err(TableError::DuplicateId(player.id));
+            }
+        }
+        Ok(Table { game, players })
+    }
+
+    /// Seats players with the given names at a game, in the order given
+    ///
+    /// Each player's ID is their seat.
+    /// Returns an error unless exactly one name is given for each seat
+    pub fn with_names<I, S>(game: Game<N>, names: I) -> Result<Self, TableError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let players = names
+            .into_iter()
+            .enumerate()
+            .map(|(seat, name)| (PlayerId(seat as u64), name));
+        Table::new(game, players)
+    }
+
+    /// Seats players named "Player 1", "Player 2", and so on at a game
+    ///
+    /// Each player's ID is their seat
+    pub fn with_default_names(game: Game<N>) -> Self {
+        let names = (1..=N).map(|number| format!("Player {}", number));
+        Table::with_names(game, names).expect("One name per seat")
+    }
+
+    /// Shuffles which seat each player sits in
+    ///
+    /// The seating is random, but decided by the game's seed, so a seeded game
+    /// always seats the same players in the same seats.
+    /// Only the players move, not their cards, so an error is returned if the
+    /// game has started, e.g.:
+    ///
+    /// ```
+    /// # use game::*;
+    /// let names = ["Alice", "Bob", "Carol"];
+    /// let table = Table::with_names(Game::<3>::with_seed(8), names)
+    ///     .unwrap()
+    ///     .shuffle_seats()
+    ///     .unwrap();
+    /// ```
+    pub fn shuffle_seats(mut self) -> Result<Self, TableError> {
+        let game = &self.game;
+        let started = !game.history().is_empty()
+            || game.round() > 0
+            || game.cards_played().iter().any(|cards| !cards.is_empty());
+        if started {
+            return Err(TableError::GameStarted);
+        }
+        let mut rng = GameRng::new(self.game.seed() ^ SEATING_SALT);
+        rng.shuffle(&mut self.players);
+        for (seat, player) in self.players.iter_mut().enumerate() {
+            player.seat = seat;
+        }
+        Ok(self)
+    }
+
+    /// Gets the game being played
+    pub const fn game(&self) -> &Game<N> {
+        &self.game
+    }
+
+    /// Gets the game being played, to make changes that don't affect who's
+    /// playing (e.g. [`Game::set_undo_depth()`])
+    pub fn game_mut(&mut self) -> &mut Game<N> {
+        &mut self.game
+    }
+
+    /// Gets the game being played, forgetting who was playing it
+    pub fn into_game(self) -> Game<N> {
+        self.game
+    }
+
+    /// Gets a slice of the players, in seat order
+    ///
+    /// Length will be equal to the number of players
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    /// Gets the player in the given seat
+    ///
+    /// Panics if `seat` is out of range
+    pub fn player(&self, seat: usize) -> &Player {
+        &self.players[seat]
+    }
+
+    /// Gets the player with the given ID, if they're at the table
+    pub fn player_by_id(&self, id: PlayerId) -> Option<&Player> {
+        self.players.iter().find(|player| player.id == id)
+    }
+
+    /// Finds out what the game needs next, the same as [`Game::what_next()`]
+    ///
+    /// Use [`named()`](Table::named()) to describe the event
+    pub fn what_next(&mut self) -> Result<Event, EngineError> {
+        self.game.what_next()
+    }
+
+    /// Gives the game a response, the same as [`Game::respond()`]
+    pub fn respond(&mut self, response: Response) -> Result<(), ResponseError> {
+        self.game.respond(response)
+    }
+
+    /// Pairs something from the game with the table, so it can be displayed
+    /// using players' names instead of their seats
+    ///
+    /// [`Event`]s, [`State`]s and [`HistoryEntry`]s can be displayed this way
+    pub const fn named<T>(&self, value: T) -> Named<'_, T, N> {
+        Named { table: self, value }
+    }
+}
+
+/// Something from a [`Game`], displayed using the names of the players at a
+/// [`Table`]
+///
+/// Created by [`Table::named()`]
+///
+/// A [`HistoryEntry`] recording a [`Response::PlayCard`] doesn't say which
+/// card was played, so that every player can be shown it
+#[derive(Debug, Copy, Clone)]
+pub struct Named<'a, T, const N: usize> {
+    table: &'a Table<N>,
+    value: T,
+}
+
+impl<T, const N: usize> Named<'_, T, N> {
+    fn name(&self, seat: usize) -> &str {
+        self.table.player(seat).name()
+    }
+}
+
+impl<const N: usize> fmt::Display for Named<'_, Event, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value {
+            Input { player, .. } => {
+                write!(f, "Waiting on {}", self.name(player))
+            }
+            BidStarted => write!(f, "Bidding has started"),
+            ChallengeStarted => write!(f, "The challenge has started"),
+            ChallengerChoseSkull {
+                challenger,
+                skull_player,
+            } if challenger == skull_player => {
+                write!(f, "{} flipped their own skull!", self.name(challenger))
+            }
+            ChallengerChoseSkull {
+                challenger,
+                skull_player,
+            } => write!(
+                f,
+                "{} flipped {}'s skull!",
+                self.name(challenger),
+                self.name(skull_player)
+            ),
+            PlayerOut(player) => {
+                write!(f, "{} is out of cards", self.name(player))
+            }
+            ChallengeWon(player) => {
+                write!(f, "{} won the challenge", self.name(player))
+            }
+            ChallengeWonGameWon(player) => write!(
+                f,
+                "{} won the challenge and the game!",
+                self.name(player)
+            ),
+            LastPlayerStanding(player) => write!(
+                f,
+                "{} is the last player standing and wins!",
+                self.name(player)
+            ),
+        }
+    }
+}
+
+impl<const N: usize> fmt::Display for Named<'_, &State<N>, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value {
+            Playing { current_player } => {
+                write!(f, "{} is playing a card", self.name(*current_player))
+            }
+            Bidding {
+                current_bidder,
+                highest_bid,
+                highest_bidder,
+                ..
+            } => write!(
+                f,
+                "{} is bidding, the highest bid is {} by {}",
+                self.name(*current_bidder),
+                highest_bid,
+                self.name(*highest_bidder)
+            ),
+            Challenging {
+                challenger,
+                target,
+                flipped,
+            } => write!(
+                f,
+                "{} is trying to flip {} flowers, and has flipped {}",
+                self.name(*challenger),
+                target,
+                flipped.iter().map(|cards| cards.len()).sum::<usize>()
+            ),
+            Discarding {
+                challenger,
+                skull_player,
+            } if challenger == skull_player => write!(
+                f,
+                "{} is choosing a card to lose",
+                self.name(*challenger)
+            ),
+            Discarding {
+                challenger,
+                skull_player,
+            } => write!(
+                f,
+                "{} is choosing a card for {} to lose",
+                self.name(*skull_player),
+                self.name(*challenger)
+            ),
+            Finished {
+                winner,
+                reason: WinReason::ChallengesWon,
+            } => write!(
+                f,
+                "{} won the game by winning challenges",
+                self.name(*winner)
+            ),
+            Finished {
+                winner,
+                reason: WinReason::LastPlayerStanding,
+            } => write!(
+                f,
+                "{} won the game as the last player standing",
+                self.name(*winner)
+            ),
+        }
+    }
+}
+
+impl<const N: usize> fmt::Display for Named<'_, HistoryEntry, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.name(self.value.player);
+        match self.value.item {
+            HistoryItem::Response(Response::PlayCard(_)) => {
+                write!(f, "{} played a card", name)
+            }
+            HistoryItem::Response(Response::Bid(bid)) => {
+                write!(f, "{} bid {}", name, bid)
+            }
+            HistoryItem::Response(Response::Pass) => {
+                write!(f, "{} passed", name)
+            }
+            HistoryItem::Response(Response::Flip(player, index)) => write!(
+                f,
+                "{} flipped {}'s card #{}",
+                name,
+                self.name(player),
+                index + 1
+            ),
+            HistoryItem::Response(Response::Discard(_)) => {
+                write!(f, "{} chose a card for the challenger to lose", name)
+            }
+            HistoryItem::Event(event) => self.table.named(event).fmt(f),
+        }
+    }
+}
+
+/// The reasons players can't be seated at a [`Table`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TableError {
+    /// There wasn't exactly one player for each seat
+    WrongPlayerCount {
+        /// The number of seats (players in the game)
+        expected: usize,
+        /// The number of players given
+        found: usize,
+    },
+    /// More than one player was given the same ID
+    DuplicateId(PlayerId),
+    /// A player's seat didn't match their place in the list of players
+    /// (only when deserializing)
+    WrongSeat(PlayerId),
+    /// Players can't change seats once the game has started
+    GameStarted,
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableError::WrongPlayerCount { expected, found } => {
+                write!(f, "Expected {} players, {} were given", expected, found)
+            }
+            TableError::DuplicateId(id) => {
+                write!(f, "More than one player has ID {}", id.0)
+            }
+            TableError::WrongSeat(id) => {
+                write!(f, "Player with ID {} is in the wrong seat", id.0)
+            }
+            TableError::GameStarted => {
+                write!(
+                    f,
+                    "Players can't change seats once the game has started"
+                )
+            }
+        }
+    }
+}
+
+impl Error for TableError {}
@@ -0,0 +1,126 @@
+use crate::*;
+
+/// What a single player is allowed to know about a [`Game`]
+///
+/// A `PlayerView` contains the viewing player's own hand and the cards they
+/// have played, but only the number of cards other players have in their hands
+/// and on the table.
+/// The only cards of other players that are visible are those that have been
+/// flipped face-up during a challenge
+///
+/// PlayerView is generic over the number of players
+///
+/// It is expected that you would only ever get a PlayerView by calling
+/// [`Game::view_for()`], instead of creating one
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerView<const N: usize> {
+    player: usize,
+    hand: Hand,
+    cards_played: OrderedHand,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    scores: [u8; N],
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    hand_counts: [u8; N],
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    stack_heights: [usize; N],
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    revealed: [FVec<(usize, Card), MAX_HAND_SIZE>; N],
+    state: State<N>,
+}
+
+impl<const N: usize> PlayerView<N> {
+    const REVEALED_INIT: FVec<(usize, Card), MAX_HAND_SIZE> = fvec![];
+
+    pub(crate) fn new(game: &Game<N>, player: usize) -> Self {
+        assert!(player < N, "Out of range player index");
+        let cards_played = game.cards_played();
+
+        let mut hand_counts = [0; N];
+        let mut stack_heights = [0; N];
+        for (index, (hand, played)) in
+            game.hands().iter().zip(cards_played.iter()).enumerate()
+        {
+            hand_counts[index] = hand.count();
+            stack_heights[index] = played.len();
+        }
+
+        let mut revealed = [Self::REVEALED_INIT; N];
+        if let State::Challenging { flipped, .. } = game.state() {
+            for (index, indexes) in flipped.iter().enumerate() {
+                revealed[index] = indexes
+                    .iter()
+                    .map(|card_index| {
+                        (*card_index, cards_played[index][*card_index])
+                    })
+                    .collect();
+            }
+        }
+
+        let mut scores = [0; N];
+        scores.copy_from_slice(game.scores());
+
+        PlayerView {
+            player,
+            hand: game.hands()[player],
+            cards_played: FVec::from_slice(cards_played[player])
+                .expect("Played more cards than fit in a hand"),
+            scores,
+            hand_counts,
+            stack_heights,
+            revealed,
+            state: game.state().clone(),
+        }
+    }
+
+    /// Gets the index of the player this view belongs to
+    pub const fn player(&self) -> usize {
+        self.player
+    }
+
+    /// Gets the viewing player's hand
+    pub const fn hand(&self) -> &Hand {
+        &self.hand
+    }
+
+    /// Gets the cards the viewing player has played, ordered bottom to top
+    pub fn cards_played(&self) -> &[Card] {
+        self.cards_played.as_slice()
+    }
+
+    /// Gets a slice of the game's scores
+    ///
+    /// Length will be equal to the number of players
+    pub const fn scores(&self) -> &[u8] {
+        &self.scores
+    }
+
+    /// Gets a slice of the number of cards each player has in their hand
+    /// (including cards they have played)
+    ///
+    /// Length will be equal to the number of players
+    pub const fn hand_counts(&self) -> &[u8] {
+        &self.hand_counts
+    }
+
+    /// Gets a slice of the number of cards each player has on the table
+    ///
+    /// Length will be equal to the number of players
+    pub const fn stack_heights(&self) -> &[usize] {
+        &self.stack_heights
+    }
+
+    /// Gets the cards of the given player that have been flipped face-up, as
+    /// `(card_index, card)` pairs
+    ///
+    /// This will always be empty unless the game is
+    /// [challenging](State::Challenging)
+    pub fn revealed(&self, player_index: usize) -> &[(usize, Card)] {
+        self.revealed[player_index].as_slice()
+    }
+
+    /// Gets the [`State`] of the game
+    pub const fn state(&self) -> &State<N> {
+        &self.state
+    }
+}
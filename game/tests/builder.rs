@@ -0,0 +1,107 @@
+use game::Card::*;
+use game::Event::*;
+use game::Response::*;
+use game::*;
+
+use std::convert::TryFrom;
+
+#[test]
+fn defaults_to_new_game() {
+    let built: Game<3> = GameBuilder::new().seed(4).build().unwrap();
+    let new: Game<3> = Game::with_seed(4);
+    assert_eq!(built.scores(), new.scores());
+    assert_eq!(built.hands(), new.hands());
+    assert_eq!(built.cards_played(), new.cards_played());
+    assert_eq!(built.state(), new.state());
+    assert_eq!(built.config(), new.config());
+    assert!(!built.is_replayable(), "Built game shouldn't be replayable");
+}
+
+#[test]
+fn mid_game_position() {
+    let mut game: Game<3> = GameBuilder::new()
+        .scores([1, 0, 0])
+        .cards_played([fvec![Flower], fvec![Flower; 2], fvec![Skull]])
+        .state(State::Bidding {
+            current_bidder: 2,
+            highest_bid: 3,
+            highest_bidder: 1,
+            max_bid: 4,
+            passed: [true, false, false],
+        })
+        .round(3)
+        .build()
+        .unwrap();
+    assert_eq!(game.round(), 3);
+    game.respond(Pass).unwrap();
+    assert_eq!(game.what_next().unwrap(), ChallengeStarted);
+    // Challenger's own two flowers are flipped, one more is needed
+    game.respond(Flip(0, 0)).unwrap();
+    assert_eq!(game.what_next().unwrap(), ChallengeWon(1));
+}
+
+#[test]
+fn pending_event() {
+    let mut game: Game<3> = GameBuilder::new()
+        .cards_played([fvec![Flower], fvec![Flower], fvec![Flower]])
+        .state(State::Bidding {
+            current_bidder: 1,
+            highest_bid: 1,
+            highest_bidder: 0,
+            max_bid: 3,
+            passed: [false; 3],
+        })
+        .pending_event(BidStarted)
+        .build()
+        .unwrap();
+    assert_eq!(game.what_next().unwrap(), BidStarted);
+}
+
+#[test]
+fn hands_from_config() {
+    let starting_hand = Hand::try_from([Flower, Skull]).unwrap();
+    let game: Game<4> = GameBuilder::new()
+        .config(GameConfig {
+            starting_hand,
+            ..GameConfig::default()
+        })
+        .build()
+        .unwrap();
+    assert!(
+        game.hands().iter().all(|hand| *hand == starting_hand),
+        "Players didn't get the starting hand"
+    );
+}
+
+#[test]
+fn invalid_position() {
+    let violations = GameBuilder::<3>::new()
+        .scores([3, 0, 0])
+        .state(State::Playing { current_player: 5 })
+        .build()
+        .unwrap_err();
+    assert!(
+        violations
+            .iter()
+            .any(|v| matches!(v, InvariantViolation::Scores(_))),
+        "Score above points to win not reported"
+    );
+    assert!(
+        violations
+            .iter()
+            .any(|v| matches!(v, InvariantViolation::State(_))),
+        "Out of range current player not reported"
+    );
+}
+
+#[test]
+fn invalid_player_count() {
+    let violations = GameBuilder::<11>::new().build().unwrap_err();
+    assert!(
+        violations
+            .iter()
+            .any(|v| matches!(v, InvariantViolation::Config(_))),
+        "Too many players not reported"
+    );
+    assert!(GameBuilder::<1>::new().build().is_err());
+}
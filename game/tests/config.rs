@@ -0,0 +1,123 @@
+use game::Card::*;
+use game::Event::*;
+use game::Response::*;
+use game::*;
+
+use std::convert::TryFrom;
+
+fn play_round<const N: usize>(game: &mut Game<N>, bid: usize) {
+    for _ in 0..N {
+        game.respond(PlayCard(Flower)).unwrap();
+    }
+    game.respond(Bid(bid)).unwrap();
+}
+
+#[test]
+fn points_to_win() {
+    let mut game: Game<3> = Game::with_config(GameConfig {
+        points_to_win: 1,
+        ..GameConfig::default()
+    });
+    play_round(&mut game, 3);
+    assert_eq!(game.what_next().unwrap(), ChallengeStarted);
+    game.respond(Flip(1, 0)).unwrap();
+    game.respond(Flip(2, 0)).unwrap();
+    assert_eq!(
+        game.what_next().unwrap(),
+        ChallengeWonGameWon(0),
+        "One point should have won the game"
+    );
+    assert_eq!(game.winner(), Some(0));
+}
+
+#[test]
+fn starting_hand() {
+    let starting_hand = Hand::try_from([Flower, Flower]).unwrap();
+    let mut game: Game<3> = Game::with_config(GameConfig {
+        starting_hand,
+        ..GameConfig::default()
+    });
+    assert!(
+        game.hands().iter().all(|hand| *hand == starting_hand),
+        "Players didn't get the starting hand"
+    );
+    assert_eq!(
+        game.respond(PlayCard(Skull)),
+        Err(ResponseError::CardNotInHand {
+            player: 0,
+            card: Skull
+        }),
+        "Played a skull that wasn't in the starting hand"
+    );
+}
+
+#[test]
+fn challenger_flips_in_any_order() {
+    let mut game: Game<3> = Game::with_config(GameConfig {
+        challenger_flips_own_first: false,
+        ..GameConfig::default()
+    });
+    play_round(&mut game, 2);
+    game.what_next().unwrap();
+    game.respond(Pass).unwrap();
+    game.respond(Pass).unwrap();
+    assert_eq!(game.what_next().unwrap(), ChallengeStarted);
+    assert!(
+        matches!(game.state(), State::Challenging { flipped, .. } if flipped.iter().all(|f| f.is_empty())),
+        "Challenger's cards were flipped automatically"
+    );
+    game.respond(Flip(2, 0)).unwrap();
+    game.respond(Flip(0, 0))
+        .expect("Challenger should be able to flip their own cards");
+    assert_eq!(game.what_next().unwrap(), ChallengeWon(0));
+}
+
+#[test]
+#[should_panic(expected = "Points to win must be at least 1")]
+fn invalid_config() {
+    let _game: Game<3> = Game::with_config(GameConfig {
+        points_to_win: 0,
+        ..GameConfig::default()
+    });
+}
+
+#[test]
+fn first_player_seat() {
+    let mut game: Game<4> = Game::with_config(GameConfig {
+        first_player: FirstPlayer::Seat(2),
+        ..GameConfig::default()
+    });
+    assert_eq!(game.state(), &State::Playing { current_player: 2 });
+    game.respond(PlayCard(Flower)).unwrap();
+    assert_eq!(game.state(), &State::Playing { current_player: 3 });
+}
+
+#[test]
+fn random_first_player() {
+    let config = GameConfig {
+        first_player: FirstPlayer::Random,
+        ..GameConfig::default()
+    };
+    let first_player = |seed| {
+        let mut game: Game<5> = Game::with_config(config);
+        game.reseed(seed);
+        match game.state() {
+            State::Playing { current_player } => *current_player,
+            state => panic!("New game wasn't playing: {:?}", state),
+        }
+    };
+    assert_eq!(first_player(3), first_player(3), "Seed wasn't used");
+    assert!(
+        (0..20).any(|seed| first_player(seed) != 0),
+        "First player was never chosen at random"
+    );
+}
+
+#[test]
+#[should_panic(expected = "First player's seat is out of range")]
+fn first_player_out_of_range() {
+    let _game: Game<3> = Game::with_config(GameConfig {
+        first_player: FirstPlayer::Seat(3),
+        ..GameConfig::default()
+    });
+}
@@ -0,0 +1,51 @@
+use game::Card::*;
+use game::Event::*;
+use game::Response::*;
+use game::*;
+
+#[test]
+fn player_counts() {
+    for player_count in MIN_PLAYERS..=MAX_PLAYERS {
+        let game = DynGame::new(player_count).expect("Supported player count");
+        assert_eq!(game.player_count(), player_count);
+        assert_eq!(game.scores().len(), player_count);
+    }
+    assert!(DynGame::new(1).is_none(), "Created game for 1 player");
+    assert!(DynGame::new(11).is_none(), "Created game for 11 players");
+}
+
+#[test]
+fn plays_like_game() {
+    let mut dyn_game = DynGame::with_seed(4, 9).unwrap();
+    let mut game: Game<4> = Game::with_seed(9);
+    for _ in 0..4 {
+        dyn_game.respond(PlayCard(Flower)).unwrap();
+        game.respond(PlayCard(Flower)).unwrap();
+    }
+    dyn_game.respond(Bid(4)).unwrap();
+    game.respond(Bid(4)).unwrap();
+    assert_eq!(dyn_game.what_next().unwrap(), ChallengeStarted);
+    assert_eq!(game.what_next().unwrap(), ChallengeStarted);
+    assert_eq!(dyn_game.what_next().unwrap(), game.what_next().unwrap());
+
+    let inner = dyn_game.as_game::<4>().expect("Game should have 4 players");
+    assert_eq!(inner.state(), game.state());
+    assert_eq!(inner.history(), game.history());
+    assert!(
+        dyn_game.as_game::<3>().is_none(),
+        "Game doesn't have 3 players"
+    );
+}
+
+#[test]
+fn from_game() {
+    let game: Game<5> = Game::with_seed(1);
+    let mut dyn_game = DynGame::from(game);
+    assert!(matches!(dyn_game, DynGame::Five(_)));
+    dyn_game
+        .as_game_mut::<5>()
+        .unwrap()
+        .respond(PlayCard(Skull))
+        .unwrap();
+    assert_eq!(dyn_game.cards_played()[0], &[Skull]);
+}
@@ -39,7 +39,13 @@ fn incorrect_input_type() {
         None,
     );
     let err = game.respond(Bid(6)).unwrap_err();
-    assert_eq!(err, IncorrectInputType(InputType::FlipCard));
+    assert_eq!(
+        err,
+        IncorrectInputType {
+            expected: InputType::FlipCard,
+            response: Bid(6)
+        }
+    );
 }
 
 #[test]
@@ -52,7 +58,13 @@ fn card_not_in_hand() {
         None,
     );
     let err = game.respond(PlayCard(Skull)).unwrap_err();
-    assert_eq!(err, CardNotInHand);
+    assert_eq!(
+        err,
+        CardNotInHand {
+            player: 2,
+            card: Skull
+        }
+    );
 
     let mut game = Game::create_from(
         [0; 3],
@@ -66,7 +78,13 @@ fn card_not_in_hand() {
         None,
     );
     let err = game.respond(PlayCard(Flower)).unwrap_err();
-    assert_eq!(err, CardNotInHand);
+    assert_eq!(
+        err,
+        CardNotInHand {
+            player: 2,
+            card: Flower
+        }
+    );
 }
 
 #[test]
@@ -85,7 +103,7 @@ fn bid_too_low() {
         None,
     );
     let err = game.respond(Bid(1)).unwrap_err();
-    assert_eq!(err, BidTooLow(2));
+    assert_eq!(err, BidTooLow { bid: 1, min: 2 });
 }
 
 #[test]
@@ -105,7 +123,13 @@ fn bid_too_high() {
         None,
     );
     let err = game.respond(Bid(4)).unwrap_err();
-    assert_eq!(err, BidTooHigh(max_bid));
+    assert_eq!(
+        err,
+        BidTooHigh {
+            bid: 4,
+            max: max_bid
+        }
+    );
 }
 
 #[test]
@@ -122,7 +146,13 @@ fn invalid_index() {
         None,
     );
     let err = game.respond(Flip(3, 0)).unwrap_err();
-    assert_eq!(err, InvalidIndex);
+    assert_eq!(
+        err,
+        InvalidPlayerIndex {
+            player: 3,
+            player_count: 3
+        }
+    );
 
     let mut game = Game::create_from(
         [0; 3],
@@ -136,7 +166,34 @@ fn invalid_index() {
         None,
     );
     let err = game.respond(Flip(1, 3)).unwrap_err();
-    assert_eq!(err, InvalidIndex);
+    assert_eq!(
+        err,
+        InvalidIndex {
+            player: 1,
+            index: 3,
+            len: 2
+        }
+    );
+
+    let mut game = Game::create_from(
+        [0; 3],
+        [Hand::new(), Hand::new(), Hand::try_from([Flower]).unwrap()],
+        [fvec![Skull], fvec![Flower], fvec![Flower]],
+        State::Discarding {
+            challenger: 2,
+            skull_player: 0,
+        },
+        None,
+    );
+    let err = game.respond(Discard(1)).unwrap_err();
+    assert_eq!(
+        err,
+        InvalidIndex {
+            player: 2,
+            index: 1,
+            len: 1
+        }
+    );
 }
 
 #[test]
@@ -153,7 +210,13 @@ fn card_already_flipped() {
         None,
     );
     let err = game.respond(Flip(1, 0)).unwrap_err();
-    assert_eq!(err, CardAlreadyFlipped);
+    assert_eq!(
+        err,
+        CardAlreadyFlipped {
+            player: 1,
+            index: 0
+        }
+    );
 }
 
 #[test]
@@ -171,5 +234,153 @@ fn manually_flipping_own_cards() {
         None,
     );
     let err = game.respond(Flip(challenger, 0)).unwrap_err();
-    assert_eq!(err, ManuallyFlippingOwnCards);
+    assert_eq!(
+        err,
+        ManuallyFlippingOwnCards {
+            player: challenger,
+            index: 0
+        }
+    );
+}
+
+#[test]
+fn game_over() {
+    let mut game = Game::create_from(
+        [2, 0, 1],
+        [Hand::new(); 3],
+        [fvec![Flower; 2], fvec![Flower; 2], fvec![Flower; 2]],
+        State::Finished {
+            winner: 0,
+            reason: WinReason::ChallengesWon,
+        },
+        None,
+    );
+    let err = game.respond(PlayCard(Flower)).unwrap_err();
+    assert_eq!(err, GameOver);
+}
+
+#[test]
+fn validate_matches_respond() {
+    let game = Game::create_from(
+        [0; 3],
+        [Hand::new(); 3],
+        [fvec![Flower], fvec![Flower], fvec![Skull]],
+        State::Playing { current_player: 0 },
+        None,
+    );
+    assert_eq!(game.validate(Bid(0)), Err(BidTooLow { bid: 0, min: 1 }));
+    assert_eq!(game.validate(Bid(4)), Err(BidTooHigh { bid: 4, max: 3 }));
+    assert_eq!(
+        game.validate(Pass),
+        Err(IncorrectInputType {
+            expected: InputType::PlayCardOrStartBid,
+            response: Pass
+        })
+    );
+    assert_eq!(game.validate(Bid(2)), Ok(()));
+
+    for response in [Bid(0), Bid(4), Pass, Discard(0), Bid(2)] {
+        assert_eq!(
+            game.validate(response),
+            game.clone().respond(response),
+            "Validation disagrees with respond for {:?}",
+            response
+        );
+    }
+}
+
+#[test]
+fn display_includes_context() {
+    let err = IncorrectInputType {
+        expected: InputType::BidOrPass,
+        response: Flip(1, 2),
+    };
+    assert_eq!(
+        err.to_string(),
+        "Incorrect input type, expected Bid or Pass but got Flip(1, 2)"
+    );
+    let err = InvalidIndex {
+        player: 1,
+        index: 3,
+        len: 2,
+    };
+    assert_eq!(
+        err.to_string(),
+        "Invalid index 3 for player 1's cards, needs to be less than 2"
+    );
+    let err = BidTooHigh { bid: 7, max: 5 };
+    assert_eq!(err.to_string(), "Bid of 7 too high, needs to be at most 5");
+}
+
+#[test]
+fn invariant_violations() {
+    let game: Game<3> = Game::with_seed(7);
+    assert_eq!(game.validate_state(), Ok(()));
+    assert_eq!(game.check_invariants(), Ok(()));
+
+    let violations = GameBuilder::<3>::new()
+        .scores([5, 0, 0])
+        .hands([
+            Hand::new(),
+            Hand::try_from([Skull, Skull]).unwrap(),
+            Hand::new(),
+        ])
+        .state(State::Playing { current_player: 7 })
+        .build()
+        .unwrap_err();
+    assert!(
+        matches!(violations[0], InvariantViolation::Scores(_)),
+        "Score above points to win not reported"
+    );
+    assert!(
+        matches!(violations[1], InvariantViolation::Hands(_)),
+        "Hand not from starting hand not reported"
+    );
+    assert!(
+        matches!(violations.last(), Some(InvariantViolation::State(_))),
+        "Out of range player not reported"
+    );
+}
+
+#[test]
+fn skull_flip_players_validated() {
+    let skull_flipped = |challenger, skull_player| {
+        GameBuilder::<3>::new()
+            .cards_played([fvec![Flower], fvec![Skull], fvec![Flower]])
+            .state(State::Challenging {
+                challenger: 0,
+                target: 3,
+                flipped: [fvec![0], fvec![0], fvec![]],
+            })
+            .pending_event(Event::ChallengerChoseSkull {
+                challenger,
+                skull_player,
+            })
+            .build()
+            .and_then(|game| game.validate_state())
+    };
+    assert_eq!(skull_flipped(0, 1), Ok(()));
+    for (challenger, skull_player) in [(7, 1), (0, 7), (2, 1), (0, 2)] {
+        let violations = skull_flipped(challenger, skull_player).unwrap_err();
+        assert!(
+            violations
+                .iter()
+                .any(|v| matches!(v, InvariantViolation::PendingEvent(_))),
+            "Skull flip by {} of {}'s card not reported",
+            challenger,
+            skull_player
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "Invalid cards played")]
+fn invalid_game_created() {
+    Game::create_from(
+        [0; 3],
+        [Hand::try_from([Flower]).unwrap(), Hand::new(), Hand::new()],
+        [fvec![Skull], fvec![], fvec![]],
+        State::Playing { current_player: 1 },
+        None,
+    );
 }
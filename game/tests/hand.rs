@@ -0,0 +1,91 @@
+use game::Card::*;
+use game::*;
+
+use std::convert::TryFrom;
+
+#[test]
+fn multiple_skulls() {
+    let hand = Hand::try_from([Skull, Flower, Skull, Flower]).unwrap();
+    assert_eq!(hand.count(), 4);
+    assert_eq!(hand.count_of(Skull), 2);
+    assert_eq!(hand.count_of(Flower), 2);
+    assert_eq!(
+        hand.as_vec().as_slice(),
+        &[Flower, Flower, Skull, Skull],
+        "Cards should be listed in the order of Card::ALL"
+    );
+
+    let remaining = (hand - &[Skull, Flower][..]).unwrap();
+    assert_eq!(remaining, Hand::try_from([Flower, Skull]).unwrap());
+    assert!(
+        (remaining - &[Skull, Skull][..]).is_err(),
+        "Subtracted cards that weren't in the hand"
+    );
+}
+
+#[test]
+fn too_many_cards() {
+    assert!(Hand::try_from([Flower; MAX_HAND_SIZE]).is_ok());
+    assert!(
+        Hand::try_from([Flower; MAX_HAND_SIZE + 1]).is_err(),
+        "Hand bigger than the maximum created"
+    );
+}
+
+#[test]
+fn extra_skull_game() {
+    let starting_hand =
+        Hand::try_from([Skull, Skull, Flower, Flower, Flower]).unwrap();
+    let mut game: Game<3> = Game::with_config(GameConfig {
+        starting_hand,
+        ..GameConfig::default()
+    });
+    for _ in 0..2 {
+        for _ in 0..3 {
+            game.respond(Response::PlayCard(Skull)).unwrap();
+        }
+    }
+    assert_eq!(
+        game.respond(Response::PlayCard(Skull)),
+        Err(ResponseError::CardNotInHand {
+            player: 0,
+            card: Skull
+        }),
+        "Played a third skull"
+    );
+    game.respond(Response::PlayCard(Flower)).unwrap();
+    assert_eq!(game.cards_played()[0], &[Skull, Skull, Flower]);
+}
+
+#[test]
+fn allowed_by_config() {
+    let config = GameConfig::default();
+    assert!(config.allows(Hand::new()));
+    assert_eq!(
+        config.hand_from(&[Skull, Flower]),
+        Ok(Hand::try_from([Flower, Skull]).unwrap())
+    );
+    let two_skulls = Hand::try_from([Skull, Skull]).unwrap();
+    assert!(!config.allows(two_skulls), "Rules allowed an extra skull");
+    assert_eq!(
+        config.hand_from(&[Skull, Skull]),
+        Err(HandError::NotAllowed(two_skulls))
+    );
+    assert_eq!(
+        config.hand_from(&[Flower; MAX_HAND_SIZE + 1]),
+        Err(HandError::TooManyCards)
+    );
+
+    let config = GameConfig {
+        starting_hand: two_skulls,
+        ..GameConfig::default()
+    };
+    assert!(config.allows(two_skulls));
+    assert!(!config.allows(Hand::new()), "Rules allowed flowers");
+}
+
+#[test]
+fn skulls_lose_challenges() {
+    assert!(Skull.loses_challenge());
+    assert!(!Flower.loses_challenge());
+}
@@ -0,0 +1,131 @@
+use game::Card::*;
+use game::Event::*;
+use game::HistoryItem::*;
+use game::Response::*;
+use game::*;
+
+use heapless::Vec as FVec;
+
+#[test]
+fn responses_recorded() {
+    let mut game: Game<3> = Game::new();
+    game.respond(PlayCard(Flower)).unwrap();
+    game.respond(PlayCard(Skull)).unwrap();
+    // Rejected responses shouldn't be recorded
+    game.respond(Pass).unwrap_err();
+    game.respond(PlayCard(Flower)).unwrap();
+
+    let expected = [
+        (0, PlayCard(Flower)),
+        (1, PlayCard(Skull)),
+        (2, PlayCard(Flower)),
+    ];
+    assert_eq!(
+        game.history().len(),
+        expected.len(),
+        "Incorrect number of history entries"
+    );
+    for (entry, (player, response)) in game.history().iter().zip(expected) {
+        assert_eq!(
+            entry,
+            &HistoryEntry {
+                round: 0,
+                player,
+                item: Response(response),
+            },
+            "Incorrect history entry"
+        );
+    }
+}
+
+#[test]
+fn events_recorded() {
+    let mut game: Game<3> = Game::new();
+    for _ in 0..3 {
+        game.respond(PlayCard(Flower)).unwrap();
+    }
+    game.respond(Bid(2)).unwrap();
+    // Input events shouldn't be recorded
+    assert_eq!(game.what_next().unwrap(), BidStarted);
+    game.what_next().unwrap();
+    game.what_next().unwrap();
+    game.respond(Pass).unwrap();
+    game.respond(Pass).unwrap();
+    assert_eq!(game.what_next().unwrap(), ChallengeStarted);
+    game.respond(Flip(1, 0)).unwrap();
+    assert_eq!(game.what_next().unwrap(), ChallengeWon(0));
+
+    let events = game
+        .history()
+        .iter()
+        .filter(|entry| matches!(entry.item, Event(_)))
+        .copied()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        events,
+        vec![
+            HistoryEntry {
+                round: 0,
+                player: 0,
+                item: Event(BidStarted),
+            },
+            HistoryEntry {
+                round: 0,
+                player: 0,
+                item: Event(ChallengeStarted),
+            },
+            HistoryEntry {
+                round: 0,
+                player: 0,
+                item: Event(ChallengeWon(0)),
+            },
+        ],
+        "Incorrect events recorded"
+    );
+    assert_eq!(game.round(), 1, "Round didn't end with challenge");
+
+    game.respond(PlayCard(Skull)).unwrap();
+    assert_eq!(
+        game.history().last().unwrap().round,
+        1,
+        "Response recorded against incorrect round"
+    );
+}
+
+#[test]
+fn chosen_discard_recorded() {
+    let skull_player = 1;
+    let mut game = Game::create_from(
+        [0; 3],
+        [Hand::new(); 3],
+        [
+            FVec::from_slice(&[Flower]).unwrap(),
+            FVec::from_slice(&[Skull]).unwrap(),
+            FVec::from_slice(&[Flower]).unwrap(),
+        ],
+        State::Challenging {
+            challenger: 0,
+            target: 2,
+            flipped: [
+                FVec::from_slice(&[0]).unwrap(),
+                FVec::new(),
+                FVec::new(),
+            ],
+        },
+        None,
+    );
+    game.set_discard_mode(DiscardMode::Chosen);
+    game.respond(Flip(1, 0)).unwrap();
+    game.what_next().unwrap();
+    game.respond(Discard(0)).unwrap();
+    assert_eq!(game.round(), 1, "Round didn't end with discard");
+    assert_eq!(
+        game.history().last().unwrap(),
+        &HistoryEntry {
+            round: 0,
+            player: skull_player,
+            item: Response(Discard(0)),
+        },
+        "Discard recorded against incorrect round"
+    );
+}
@@ -80,7 +80,7 @@ mod playing {
             panic!("Game should still be bidding");
         }
         assert_eq!(
-            game_two.what_next(),
+            game_two.what_next().unwrap(),
             BidStarted,
             "BidStarted event not fired"
         );
@@ -130,7 +130,11 @@ mod playing {
         } else {
             panic!("Game should still be bidding");
         }
-        assert_eq!(game.what_next(), BidStarted, "BidStarted event not fired");
+        assert_eq!(
+            game.what_next().unwrap(),
+            BidStarted,
+            "BidStarted event not fired"
+        );
     }
 
     #[test]
@@ -160,7 +164,7 @@ mod playing {
             panic!("Game state changed for no reason");
         }
         assert_ne!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 1,
                 input: InputType::PlayCardOrStartBid,
@@ -168,7 +172,7 @@ mod playing {
             "Current player is out and should have been skipped (input request)"
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 2,
                 input: InputType::PlayCardOrStartBid,
@@ -201,7 +205,7 @@ mod playing {
             panic!("Game state changed for no reason");
         }
         assert_ne!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 2,
                 input: InputType::PlayCardOrStartBid,
@@ -209,7 +213,7 @@ mod playing {
             "Current player is out and should have been skipped (input request)"
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 0,
                 input: InputType::PlayCardOrStartBid,
@@ -242,7 +246,7 @@ mod playing {
             panic!("Game state changed for no reason");
         }
         assert_ne!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 0,
                 input: InputType::PlayCardOrStartBid,
@@ -250,7 +254,7 @@ mod playing {
             "Current player is out and should have been skipped (input request)"
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 1,
                 input: InputType::PlayCardOrStartBid,
@@ -291,7 +295,7 @@ mod playing {
             panic!("Game state changed for no reason");
         }
         assert_ne!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 1,
                 input: InputType::PlayCard,
@@ -299,18 +303,19 @@ mod playing {
             "Current player is out and should have been skipped (input request)"
         );
         assert_ne!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 2,
                 input: InputType::PlayCard,
             },
             "Current player is out and should have been skipped (input request)"
         );
+        // Everyone still in has played a card, so bidding can start
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 3,
-                input: InputType::PlayCard,
+                input: InputType::PlayCardOrStartBid,
             },
             "Current player is incorrect (but not a player with no cards) (input request)"
         );
@@ -345,7 +350,7 @@ mod playing {
             panic!("Game state changed for no reason");
         }
         assert_ne!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 3,
                 input: InputType::PlayCard,
@@ -353,7 +358,7 @@ mod playing {
             "Current player is out and should have been skipped (input request)"
         );
         assert_ne!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 0,
                 input: InputType::PlayCard,
@@ -361,10 +366,10 @@ mod playing {
             "Current player is out and should have been skipped (input request)"
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 1,
-                input: InputType::PlayCard,
+                input: InputType::PlayCardOrStartBid,
             },
             "Current player is incorrect (but not a player with no cards) (input request)"
         );
@@ -416,7 +421,7 @@ mod bidding {
         game.respond(Bid(3))
             .expect("Game should have accepted the response");
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             ChallengeStarted,
             "ChallengeStarted event not fired"
         );
@@ -472,7 +477,7 @@ mod bidding {
             .expect("Game should have accepted the response");
 
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             ChallengeStarted,
             "ChallengeStarted event not fired"
         );
@@ -525,7 +530,7 @@ mod bidding {
             panic!("Game state changed for no reason");
         }
         assert_ne!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 1,
                 input: InputType::BidOrPass,
@@ -533,7 +538,7 @@ mod bidding {
             "Current player is out and should have been skipped (input request)"
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 2,
                 input: InputType::BidOrPass,
@@ -576,7 +581,7 @@ mod bidding {
             panic!("Game state changed for no reason");
         }
         assert_ne!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 2,
                 input: InputType::BidOrPass,
@@ -584,7 +589,7 @@ mod bidding {
             "Current player is out and should have been skipped (input request)"
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 0,
                 input: InputType::BidOrPass,
@@ -627,7 +632,7 @@ mod bidding {
             panic!("Game state changed for no reason");
         }
         assert_ne!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 0,
                 input: InputType::BidOrPass,
@@ -635,7 +640,7 @@ mod bidding {
             "Current player is out and should have been skipped (input request)"
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 1,
                 input: InputType::BidOrPass,
@@ -687,7 +692,7 @@ mod bidding {
             panic!("Game state changed for no reason");
         }
         assert_ne!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 1,
                 input: InputType::BidOrPass,
@@ -695,7 +700,7 @@ mod bidding {
             "Current player is out and should have been skipped (input request)"
         );
         assert_ne!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 2,
                 input: InputType::BidOrPass,
@@ -703,7 +708,7 @@ mod bidding {
             "Current player is out and should have been skipped (input request)"
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 3,
                 input: InputType::BidOrPass,
@@ -752,7 +757,7 @@ mod bidding {
             panic!("Game state changed for no reason");
         }
         assert_ne!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 3,
                 input: InputType::BidOrPass,
@@ -760,7 +765,7 @@ mod bidding {
             "Current player is out and should have been skipped (input request)"
         );
         assert_ne!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 0,
                 input: InputType::BidOrPass,
@@ -768,7 +773,7 @@ mod bidding {
             "Current player is out and should have been skipped (input request)"
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 1,
                 input: InputType::BidOrPass,
@@ -801,7 +806,7 @@ mod challenging {
             None,
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: challenger,
                 input: InputType::FlipCard,
@@ -812,7 +817,7 @@ mod challenging {
         game.respond(Response::Flip(1, 1))
             .expect("Game should have accepted the response");
         if let State::Challenging { flipped, .. } = game.state() {
-            let expected: &[FVec<usize, 4>; 3] =
+            let expected: &[FVec<usize, MAX_HAND_SIZE>; 3] =
                 &[fvec![0, 1], fvec![1], fvec![]];
             assert_eq!(
                 flipped, expected,
@@ -822,7 +827,7 @@ mod challenging {
             panic!("Game state not challenging when it was given no reason to change (1)");
         }
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: challenger,
                 input: InputType::FlipCard,
@@ -833,7 +838,7 @@ mod challenging {
         game.respond(Response::Flip(1, 0))
             .expect("Game should have accepted the response");
         if let State::Challenging { flipped, .. } = game.state() {
-            let expected: &[FVec<usize, 4>; 3] =
+            let expected: &[FVec<usize, MAX_HAND_SIZE>; 3] =
                 &[fvec![0, 1], fvec![1, 0], fvec![]];
             assert_eq!(
                 flipped, expected,
@@ -843,7 +848,7 @@ mod challenging {
             panic!("Game state not challenging when it was given no reason to change (2)");
         }
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: challenger,
                 input: InputType::FlipCard,
@@ -854,7 +859,7 @@ mod challenging {
         game.respond(Response::Flip(2, 0))
             .expect("Game should have accepted the response");
         if let State::Challenging { flipped, .. } = game.state() {
-            let expected: &[FVec<usize, 4>; 3] =
+            let expected: &[FVec<usize, MAX_HAND_SIZE>; 3] =
                 &[fvec![0, 1], fvec![1, 0], fvec![0]];
             assert_eq!(
                 flipped, expected,
@@ -864,7 +869,7 @@ mod challenging {
             panic!("Game state not challenging when it was given no reason to change (3)");
         }
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: challenger,
                 input: InputType::FlipCard,
@@ -887,8 +892,9 @@ mod challenging {
             },
             None,
         );
+        game.set_discard_mode(DiscardMode::Random);
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: challenger,
                 input: InputType::FlipCard,
@@ -898,7 +904,7 @@ mod challenging {
         game.respond(Response::Flip(2, 1))
             .expect("Game should have accepted the response");
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             ChallengerChoseSkull {
                 challenger,
                 skull_player: 2,
@@ -911,7 +917,7 @@ mod challenging {
             "Challenger didn't have a card discarded"
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: 2,
                 input: InputType::PlayCard,
@@ -940,10 +946,11 @@ mod challenging {
             },
             None,
         );
+        game.set_discard_mode(DiscardMode::Random);
         game.respond(Response::Flip(0, 0))
             .expect("Game should have accepted the response");
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             ChallengerChoseSkull {
                 challenger,
                 skull_player,
@@ -956,7 +963,7 @@ mod challenging {
             "Challenger didn't have a card discarded"
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             PlayerOut(challenger),
             "{:?} event not fired",
             PlayerOut(challenger),
@@ -967,7 +974,7 @@ mod challenging {
             "Cards played didn't reset"
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: skull_player,
                 input: InputType::PlayCard,
@@ -976,6 +983,110 @@ mod challenging {
         );
     }
 
+    #[test]
+    fn challenge_lost_last_player_standing() {
+        let challenger = 2;
+        let skull_player = 0;
+        let mut game = Game::create_from(
+            [0; 3],
+            [
+                Hand::new(),
+                Hand::default(),
+                Hand::try_from([Flower]).unwrap(),
+            ],
+            [fvec![Skull], fvec![], fvec![Flower]],
+            State::Challenging {
+                challenger,
+                target: 2,
+                flipped: [fvec![], fvec![], fvec![0]],
+            },
+            None,
+        );
+        game.set_discard_mode(DiscardMode::Random);
+        game.respond(Response::Flip(0, 0))
+            .expect("Game should have accepted the response");
+        assert_eq!(
+            game.what_next().unwrap(),
+            ChallengerChoseSkull {
+                challenger,
+                skull_player,
+            },
+            "ChallengerChoseSkull event not fired",
+        );
+        assert_eq!(
+            game.what_next().unwrap(),
+            PlayerOut(challenger),
+            "{:?} event not fired",
+            PlayerOut(challenger),
+        );
+        assert_eq!(game.winner(), None, "Game over before being announced");
+        assert_eq!(
+            game.what_next().unwrap(),
+            LastPlayerStanding(skull_player),
+            "{:?} event not fired",
+            LastPlayerStanding(skull_player),
+        );
+        assert_eq!(
+            game.winner(),
+            Some(skull_player),
+            "Last player standing not declared winner"
+        );
+        assert_eq!(
+            game.what_next().unwrap(),
+            LastPlayerStanding(skull_player),
+            "Game continued after last player standing"
+        );
+        assert_eq!(
+            game.respond(Response::PlayCard(Flower)),
+            Err(ResponseError::GameOver),
+            "Game accepted input after last player standing"
+        );
+    }
+
+    #[test]
+    fn challenge_lost_seeded() {
+        let challenger = 0;
+        let game = Game::create_from(
+            [0; 3],
+            [Hand::new(); 3],
+            [fvec![Flower; 2], fvec![Flower; 2], fvec![Flower, Skull]],
+            State::Challenging {
+                challenger,
+                target: 5,
+                flipped: [fvec![0, 1], fvec![1, 0], fvec![]],
+            },
+            None,
+        );
+        let lose_challenge = |seed| {
+            let mut game = game.clone();
+            game.reseed(seed);
+            game.set_discard_mode(DiscardMode::Random);
+            game.respond(Response::Flip(2, 1))
+                .expect("Game should have accepted the response");
+            game.hands()[challenger]
+        };
+
+        let mut skull_discarded = false;
+        let mut flower_discarded = false;
+        for seed in 0..32 {
+            let hand = lose_challenge(seed);
+            assert_eq!(
+                hand,
+                lose_challenge(seed),
+                "Same seed discarded different cards"
+            );
+            if hand.has_skull() {
+                flower_discarded = true;
+            } else {
+                skull_discarded = true;
+            }
+        }
+        assert!(
+            skull_discarded && flower_discarded,
+            "Discard wasn't random across seeds"
+        );
+    }
+
     #[test]
     fn challenge_won() {
         let challenger = 0;
@@ -991,7 +1102,7 @@ mod challenging {
             None,
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: challenger,
                 input: InputType::FlipCard,
@@ -1001,7 +1112,7 @@ mod challenging {
         game.respond(Response::Flip(2, 1))
             .expect("Game should have accepted the response");
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             ChallengeWon(challenger),
             "ChallengeWon({}) event not fired",
             challenger,
@@ -1028,7 +1139,7 @@ mod challenging {
             None,
         );
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             Input {
                 player: challenger,
                 input: InputType::FlipCard,
@@ -1038,7 +1149,7 @@ mod challenging {
         game.respond(Response::Flip(2, 1))
             .expect("Game should have accepted the response");
         assert_eq!(
-            game.what_next(),
+            game.what_next().unwrap(),
             ChallengeWonGameWon(challenger),
             "ChallengeWonGameWon({}) event not fired",
             challenger,
@@ -1048,6 +1159,19 @@ mod challenging {
             2,
             "Challenger not awarded one point"
         );
+        assert_eq!(
+            game.state(),
+            &State::Finished {
+                winner: challenger,
+                reason: WinReason::ChallengesWon,
+            },
+            "Game not finished after being won"
+        );
+        assert_eq!(
+            game.what_next().unwrap(),
+            ChallengeWonGameWon(challenger),
+            "Game continued after being won"
+        );
     }
 
     mod flipping_own_cards {
@@ -1072,7 +1196,7 @@ mod challenging {
                 Some(ChallengeStarted),
             );
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengeStarted,
                 "ChallengeStarted event not emitted (despite being provided)"
             );
@@ -1086,7 +1210,7 @@ mod challenging {
             }
 
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 Input {
                     player: challenger,
                     input: InputType::FlipCard,
@@ -1113,8 +1237,9 @@ mod challenging {
                 },
                 Some(ChallengeStarted),
             );
+            game.set_discard_mode(DiscardMode::Random);
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengeStarted,
                 "ChallengeStarted event not emitted (despite being provided)"
             );
@@ -1128,7 +1253,7 @@ mod challenging {
             }
 
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengerChoseSkull {
                     challenger,
                     skull_player: challenger,
@@ -1136,7 +1261,7 @@ mod challenging {
                 "ChallengerChoseSkull event not fired"
             );
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 Input {
                     player: challenger,
                     input: InputType::PlayCard,
@@ -1170,7 +1295,7 @@ mod challenging {
                 Some(ChallengeStarted),
             );
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengeStarted,
                 "ChallengeStarted event not emitted (despite being provided)"
             );
@@ -1184,7 +1309,7 @@ mod challenging {
             }
 
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengeWon(challenger),
                 "ChallengeWon({}) event not emitted",
                 challenger
@@ -1200,7 +1325,7 @@ mod challenging {
                 "Cards played was not reset"
             );
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 Input {
                     player: challenger,
                     input: InputType::PlayCard,
@@ -1224,7 +1349,7 @@ mod challenging {
                 Some(ChallengeStarted),
             );
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengeStarted,
                 "ChallengeStarted event not emitted (despite being provided)"
             );
@@ -1238,7 +1363,7 @@ mod challenging {
             }
 
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengeWonGameWon(challenger),
                 "ChallengeWonGameWon({}) event not emitted",
                 challenger
@@ -1268,8 +1393,9 @@ mod challenging {
                 },
                 Some(ChallengeStarted),
             );
+            game.set_discard_mode(DiscardMode::Random);
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengeStarted,
                 "ChallengeStarted event not emitted (despite being provided)"
             );
@@ -1283,7 +1409,7 @@ mod challenging {
             }
 
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengerChoseSkull {
                     challenger,
                     skull_player: challenger,
@@ -1291,7 +1417,7 @@ mod challenging {
                 "ChallengerChoseSkull event not fired"
             );
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 Input {
                     player: challenger,
                     input: InputType::PlayCard,
@@ -1310,6 +1436,33 @@ mod challenging {
             );
         }
 
+        #[test]
+        fn some_win_in_progress() {
+            // Own cards already flipped, from part way through the stack
+            let challenger = 0;
+            let mut game = Game::create_from(
+                [1, 0, 0],
+                [Hand::new(); 3],
+                [
+                    fvec![Skull, Flower],
+                    fvec![Skull, Flower],
+                    fvec![Skull, Flower],
+                ],
+                State::Challenging {
+                    challenger,
+                    target: 1,
+                    flipped: [fvec![1], fvec![], fvec![]],
+                },
+                Some(ChallengeWon(challenger)),
+            );
+            assert_eq!(
+                game.what_next().unwrap(),
+                ChallengeWon(challenger),
+                "ChallengeWon({}) event not emitted",
+                challenger
+            );
+        }
+
         #[test]
         fn some_win() {
             let challenger = 0;
@@ -1329,7 +1482,7 @@ mod challenging {
                 Some(ChallengeStarted),
             );
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengeStarted,
                 "ChallengeStarted event not emitted (despite being provided)"
             );
@@ -1343,7 +1496,7 @@ mod challenging {
             }
 
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengeWon(challenger),
                 "ChallengeWon({}) event not emitted",
                 challenger
@@ -1359,7 +1512,7 @@ mod challenging {
                 "Cards played was not reset"
             );
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 Input {
                     player: challenger,
                     input: InputType::PlayCard,
@@ -1387,7 +1540,7 @@ mod challenging {
                 Some(ChallengeStarted),
             );
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengeStarted,
                 "ChallengeStarted event not emitted (despite being provided)"
             );
@@ -1401,7 +1554,7 @@ mod challenging {
             }
 
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengeWonGameWon(challenger),
                 "ChallengeWonGameWon({}) event not emitted",
                 challenger
@@ -1427,8 +1580,9 @@ mod challenging {
                 },
                 Some(ChallengeStarted),
             );
+            game.set_discard_mode(DiscardMode::Random);
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengeStarted,
                 "ChallengeStarted event not emitted (despite being provided)"
             );
@@ -1442,7 +1596,7 @@ mod challenging {
             }
 
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 ChallengerChoseSkull {
                     challenger,
                     skull_player: challenger,
@@ -1459,12 +1613,12 @@ mod challenging {
                 "Not all of the challenger's cards have been discarded"
             );
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 PlayerOut(challenger),
                 "PlayerOut event not fired"
             );
             assert_ne!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 Input {
                     player: challenger,
                     input: InputType::PlayCard,
@@ -1472,7 +1626,7 @@ mod challenging {
                 "Playing resumed from challenger, who is out"
             );
             assert_eq!(
-                game.what_next(),
+                game.what_next().unwrap(),
                 Input {
                     player: 1,
                     input: InputType::PlayCard,
@@ -1482,3 +1636,217 @@ mod challenging {
         }
     }
 }
+
+mod discarding {
+    use game::Card::*;
+    use game::Event::*;
+    use game::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn skull_player_chooses() {
+        let challenger = 0;
+        let skull_player = 2;
+        let mut game = Game::create_from(
+            [0; 3],
+            [Hand::new(); 3],
+            [fvec![Flower; 2], fvec![Flower; 2], fvec![Flower, Skull]],
+            State::Challenging {
+                challenger,
+                target: 5,
+                flipped: [fvec![0, 1], fvec![1, 0], fvec![]],
+            },
+            None,
+        );
+        game.respond(Response::Flip(2, 1))
+            .expect("Game should have accepted the response");
+        assert_eq!(
+            game.what_next().unwrap(),
+            ChallengerChoseSkull {
+                challenger,
+                skull_player,
+            },
+            "ChallengerChoseSkull event not fired",
+        );
+        assert_eq!(
+            game.state(),
+            &State::Discarding {
+                challenger,
+                skull_player,
+            },
+            "Game didn't wait for a card to be chosen"
+        );
+        assert_eq!(
+            game.hands()[challenger].count(),
+            4,
+            "Challenger had a card discarded before one was chosen"
+        );
+        assert_eq!(
+            game.what_next().unwrap(),
+            Input {
+                player: skull_player,
+                input: InputType::ChooseDiscard,
+            },
+            "Skull player not asked to choose a card to discard"
+        );
+
+        game.respond(Response::Discard(3))
+            .expect("Game should have accepted the response");
+        assert_eq!(
+            game.hands()[challenger].count(),
+            3,
+            "Challenger didn't have a card discarded"
+        );
+        assert_eq!(
+            game.what_next().unwrap(),
+            Input {
+                player: skull_player,
+                input: InputType::PlayCard,
+            },
+            "Playing didn't resume after lost challenge (or didn't resume from correct player)"
+        );
+        assert_eq!(
+            game.cards_played(),
+            vec![&[], &[], &[]],
+            "Cards played didn't reset"
+        );
+    }
+
+    #[test]
+    fn challenger_chooses_own() {
+        let challenger = 0;
+        let mut game = Game::create_from(
+            [0; 3],
+            [Hand::new(); 3],
+            [fvec![Flower, Skull], fvec![Flower; 2], fvec![Flower; 2]],
+            State::Challenging {
+                challenger,
+                target: 5,
+                flipped: [FVec::new(), FVec::new(), FVec::new()],
+            },
+            Some(ChallengeStarted),
+        );
+        assert_eq!(game.what_next().unwrap(), ChallengeStarted);
+        assert_eq!(
+            game.what_next().unwrap(),
+            ChallengerChoseSkull {
+                challenger,
+                skull_player: challenger,
+            },
+            "ChallengerChoseSkull event not fired"
+        );
+        assert_eq!(
+            game.what_next().unwrap(),
+            Input {
+                player: challenger,
+                input: InputType::ChooseDiscard,
+            },
+            "Challenger not asked to choose a card to discard"
+        );
+
+        // Challenger isn't choosing blind, so can pick their skull
+        let skull_index = game.hands()[challenger]
+            .as_vec()
+            .iter()
+            .position(|card| *card == Skull)
+            .unwrap();
+        game.respond(Response::Discard(skull_index))
+            .expect("Game should have accepted the response");
+        assert_eq!(
+            game.hands()[challenger],
+            Hand::try_from([Flower; 3]).unwrap(),
+            "Challenger's chosen card wasn't discarded"
+        );
+    }
+
+    #[test]
+    fn player_out() {
+        let challenger = 2;
+        let skull_player = 0;
+        let mut game = Game::create_from(
+            [0; 3],
+            [Hand::new(), Hand::new(), Hand::try_from([Flower]).unwrap()],
+            [fvec![Skull], fvec![Flower], fvec![Flower]],
+            State::Challenging {
+                challenger,
+                target: 2,
+                flipped: [fvec![], fvec![], fvec![0]],
+            },
+            None,
+        );
+        game.respond(Response::Flip(0, 0))
+            .expect("Game should have accepted the response");
+        game.what_next().unwrap();
+        game.respond(Response::Discard(0))
+            .expect("Game should have accepted the response");
+        assert_eq!(
+            game.what_next().unwrap(),
+            PlayerOut(challenger),
+            "{:?} event not fired",
+            PlayerOut(challenger),
+        );
+        assert_eq!(
+            game.what_next().unwrap(),
+            Input {
+                player: skull_player,
+                input: InputType::PlayCard,
+            },
+            "Playing didn't resume after lost challenge (or didn't resume from correct player)"
+        );
+    }
+
+    #[test]
+    fn mode_not_changed_mid_discard() {
+        let challenger = 0;
+        let skull_player = 2;
+        for (mode, other_mode) in [
+            (DiscardMode::Random, DiscardMode::Chosen),
+            (DiscardMode::Chosen, DiscardMode::Random),
+        ] {
+            let mut game = Game::create_from(
+                [0; 3],
+                [Hand::new(); 3],
+                [fvec![Flower; 2], fvec![Flower; 2], fvec![Flower, Skull]],
+                State::Challenging {
+                    challenger,
+                    target: 5,
+                    flipped: [fvec![0, 1], fvec![1, 0], fvec![]],
+                },
+                None,
+            );
+            assert!(game.set_discard_mode(mode));
+            game.respond(Response::Flip(2, 1))
+                .expect("Game should have accepted the response");
+            assert!(
+                !game.set_discard_mode(other_mode),
+                "Discard mode changed with a flipped skull pending"
+            );
+            assert_eq!(game.discard_mode(), mode);
+            game.what_next().unwrap();
+            if mode == DiscardMode::Chosen {
+                assert!(
+                    !game.set_discard_mode(other_mode),
+                    "Discard mode changed while discarding"
+                );
+                game.respond(Response::Discard(0))
+                    .expect("Game should have accepted the response");
+            }
+            assert_eq!(
+                game.hands()[challenger].count(),
+                3,
+                "Challenger should lose exactly one card"
+            );
+            assert_eq!(
+                game.what_next().unwrap(),
+                Input {
+                    player: skull_player,
+                    input: InputType::PlayCard,
+                },
+                "Playing didn't resume after lost challenge"
+            );
+            assert!(game.set_discard_mode(other_mode));
+            assert_eq!(game.discard_mode(), other_mode);
+        }
+    }
+}
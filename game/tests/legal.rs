@@ -0,0 +1,113 @@
+use game::Card::*;
+use game::Event::*;
+use game::Response::*;
+use game::*;
+
+// Every response that could possibly be accepted in a game of N players
+fn candidates<const N: usize>() -> Vec<Response> {
+    let mut candidates = vec![PlayCard(Flower), PlayCard(Skull), Pass];
+    candidates.extend((0..=N * MAX_HAND_SIZE + 1).map(Bid));
+    for player_index in 0..=N {
+        candidates.extend(
+            (0..=MAX_HAND_SIZE)
+                .map(|card_index| Flip(player_index, card_index)),
+        );
+    }
+    candidates.extend((0..=MAX_HAND_SIZE).map(Discard));
+    candidates
+}
+
+fn skip_events<const N: usize>(game: &mut Game<N>) -> bool {
+    loop {
+        match game.what_next().unwrap() {
+            Input { .. } => return true,
+            _ if game.winner().is_some() => return false,
+            _ => {}
+        }
+    }
+}
+
+// Plays a game, checking the legal responses are exactly those accepted, and
+// that validation agrees with responding, at every step
+fn check_game<const N: usize>(mut game: Game<N>, mut choice: usize) {
+    let candidates = candidates::<N>();
+    while skip_events(&mut game) {
+        let legal = game.legal_responses();
+        let mut accepted = Vec::new();
+        for response in candidates.iter().copied() {
+            let result = game.clone().respond(response);
+            assert_eq!(
+                game.validate(response),
+                result,
+                "Validation disagrees with respond for {:?} in {:?}",
+                response,
+                game.state()
+            );
+            if result.is_ok() {
+                accepted.push(response);
+            }
+        }
+        assert!(
+            legal.len() == accepted.len()
+                && legal.iter().all(|response| accepted.contains(response)),
+            "Legal responses {:?} don't match accepted responses {:?} in {:?}",
+            legal,
+            accepted,
+            game.state()
+        );
+
+        // Cheap deterministic pseudo-random choice
+        choice = choice.wrapping_mul(6364136223846793005).wrapping_add(1);
+        game.respond(legal[(choice >> 33) % legal.len()]).unwrap();
+    }
+    assert!(
+        game.legal_responses().is_empty(),
+        "Game over but responses legal"
+    );
+}
+
+#[test]
+fn matches_respond() {
+    for seed in 0..20 {
+        check_game::<3>(Game::with_seed(seed), seed as usize);
+        check_game::<5>(Game::with_seed(seed), seed as usize);
+    }
+}
+
+#[test]
+fn matches_respond_with_config() {
+    let config = GameConfig {
+        challenger_flips_own_first: false,
+        discard_mode: DiscardMode::Random,
+        ..GameConfig::default()
+    };
+    for seed in 0..20 {
+        let mut game: Game<4> = Game::with_config(config);
+        game.reseed(seed);
+        check_game(game, seed as usize);
+    }
+}
+
+#[test]
+fn bid_range() {
+    let mut game: Game<3> = Game::new();
+    assert_eq!(
+        game.legal_responses(),
+        vec![PlayCard(Flower), PlayCard(Skull)],
+        "Can't bid before everyone has played"
+    );
+    for _ in 0..3 {
+        game.respond(PlayCard(Flower)).unwrap();
+    }
+    assert_eq!(
+        game.legal_responses(),
+        vec![PlayCard(Flower), PlayCard(Skull), Bid(1), Bid(2), Bid(3)]
+    );
+    game.respond(Bid(1)).unwrap();
+    assert!(
+        game.legal_responses().is_empty(),
+        "Responses legal with pending event"
+    );
+    game.what_next().unwrap();
+    assert_eq!(game.legal_responses(), vec![Bid(2), Bid(3), Pass]);
+}
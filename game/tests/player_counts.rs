@@ -0,0 +1,105 @@
+use game::Card::*;
+use game::Event::*;
+use game::Response::*;
+use game::*;
+
+#[test]
+fn two_players() {
+    let mut game: Game<2> = Game::new();
+    game.respond(PlayCard(Flower)).unwrap();
+    game.respond(PlayCard(Flower)).unwrap();
+    game.respond(Bid(1)).unwrap();
+    assert_eq!(game.what_next().unwrap(), BidStarted);
+    assert_eq!(
+        game.what_next().unwrap(),
+        Input {
+            player: 1,
+            input: InputType::BidOrPass,
+        },
+        "Other player should be bidding"
+    );
+    game.respond(Pass).unwrap();
+    assert_eq!(
+        game.what_next().unwrap(),
+        ChallengeStarted,
+        "One pass should start the challenge"
+    );
+    assert_eq!(game.what_next().unwrap(), ChallengeWon(0));
+}
+
+#[test]
+fn ten_players() {
+    let mut game: Game<10> = Game::new();
+    for _ in 0..10 {
+        game.respond(PlayCard(Flower)).unwrap();
+    }
+    game.respond(Bid(1)).unwrap();
+    assert_eq!(game.what_next().unwrap(), BidStarted);
+    for player in 1..10 {
+        assert_eq!(
+            game.what_next().unwrap(),
+            Input {
+                player,
+                input: InputType::BidOrPass,
+            },
+            "Bidding went to the wrong player"
+        );
+        game.respond(Pass).unwrap();
+    }
+    assert_eq!(game.what_next().unwrap(), ChallengeStarted);
+    assert_eq!(game.what_next().unwrap(), ChallengeWon(0));
+}
+
+#[test]
+fn players_out_at_large_table() {
+    let out = Hand::default();
+    let mut game = Game::create_from(
+        [0; 8],
+        [
+            Hand::new(),
+            out,
+            Hand::new(),
+            Hand::new(),
+            out,
+            out,
+            Hand::new(),
+            Hand::new(),
+        ],
+        [
+            fvec![Flower],
+            fvec![],
+            fvec![Flower],
+            fvec![Flower],
+            fvec![],
+            fvec![],
+            fvec![Flower],
+            fvec![Flower],
+        ],
+        State::Playing { current_player: 0 },
+        None,
+    );
+    game.respond(Bid(1)).unwrap();
+    assert_eq!(game.what_next().unwrap(), BidStarted);
+    for player in [2, 3, 6, 7] {
+        assert_eq!(
+            game.what_next().unwrap(),
+            Input {
+                player,
+                input: InputType::BidOrPass,
+            },
+            "Players that are out should be skipped"
+        );
+        game.respond(Pass).unwrap();
+    }
+    assert_eq!(
+        game.what_next().unwrap(),
+        ChallengeStarted,
+        "Challenge should start once all remaining players have passed"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid number of players")]
+fn too_many_players() {
+    let _game: Game<11> = Game::new();
+}
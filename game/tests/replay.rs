@@ -0,0 +1,222 @@
+use game::Card::*;
+use game::Event::*;
+use game::Response::*;
+use game::*;
+
+use std::convert::TryFrom;
+
+fn respond(game: &mut Game<3>, response: Response) {
+    game.respond(response).unwrap();
+    while !matches!(game.what_next().unwrap(), Input { .. }) {
+        if game.winner().is_some() {
+            break;
+        }
+    }
+}
+
+/// Plays two rounds: the first ending in player 0 losing a challenge to
+/// player 1's skull, the second in player 1 winning a challenge
+fn played_game() -> Game<3> {
+    let mut game = Game::with_seed(5);
+    for response in [
+        PlayCard(Flower),
+        PlayCard(Skull),
+        PlayCard(Flower),
+        Bid(3),
+        Flip(1, 0),
+        Discard(0),
+        PlayCard(Flower),
+        PlayCard(Flower),
+        PlayCard(Flower),
+        Bid(1),
+        Pass,
+        Pass,
+    ] {
+        respond(&mut game, response);
+    }
+    game
+}
+
+fn assert_same(replayed: &Game<3>, game: &Game<3>) {
+    assert_eq!(replayed.state(), game.state(), "State differs");
+    assert_eq!(replayed.hands(), game.hands(), "Hands differ");
+    assert_eq!(replayed.scores(), game.scores(), "Scores differ");
+    assert_eq!(replayed.history(), game.history(), "History differs");
+}
+
+#[test]
+fn replay_matches_game() {
+    let game = played_game();
+    assert_eq!(game.round(), 2, "Test game didn't play two rounds");
+
+    let mut replay = Replay::from_game(&game).expect("Game is replayable");
+    assert_eq!(replay.len(), 12);
+    assert_eq!(replay.position(), 0);
+    assert_eq!(replay.step_forward(), Some(vec![]));
+    replay.jump_to(replay.len());
+    assert!(replay.at_end());
+    assert_eq!(replay.step_forward(), None, "Stepped past end of replay");
+    assert_same(replay.game(), &game);
+}
+
+#[test]
+fn step_back() {
+    let game = played_game();
+    let mut replay = Replay::from_game(&game).unwrap();
+    replay.jump_to(6);
+    let before = replay.game().clone();
+    assert_eq!(
+        replay.step_forward(),
+        Some(vec![]),
+        "Playing a card shouldn't cause events"
+    );
+    assert!(replay.step_back());
+    assert_eq!(replay.position(), 6);
+    assert_same(replay.game(), &before);
+
+    replay.jump_to(0);
+    assert!(!replay.step_back(), "Stepped back past start of replay");
+}
+
+#[test]
+fn jump_to_round() {
+    let game = played_game();
+    let mut replay = Replay::from_game(&game).unwrap();
+    assert!(replay.jump_to_round(1));
+    assert_eq!(replay.position(), 6, "Round 1 starts after the discard");
+    assert_eq!(replay.game().round(), 1);
+    assert!(!replay.jump_to_round(3), "Game never reached round 3");
+    assert!(replay.at_end());
+}
+
+#[test]
+fn text_round_trip() {
+    let game = played_game();
+    let replay = Replay::from_game(&game).unwrap();
+    let mut file = Vec::new();
+    replay.write_to(&mut file).unwrap();
+    assert_eq!(
+        String::from_utf8(file.clone()).unwrap(),
+        "skull-replay 1\nplayers 3\nseed 5\ndiscard chosen\n\
+         points-to-win 2\nstarting-hand FFFS\nflip-own-first true\n\
+         first-player 0\nseating 0 1 2\nF S F B3 X1.0 D0 F F F B1 P P\n"
+    );
+
+    let mut read = Replay::<3>::read_from(file.as_slice()).unwrap();
+    assert_eq!(read.responses(), replay.responses());
+    read.jump_to(read.len());
+    assert_same(read.game(), &game);
+}
+
+#[test]
+fn config_round_trip() {
+    let config = GameConfig {
+        points_to_win: 1,
+        starting_hand: Hand::try_from([Flower, Flower, Skull]).unwrap(),
+        challenger_flips_own_first: false,
+        discard_mode: DiscardMode::Random,
+        first_player: FirstPlayer::Random,
+    };
+    let replay =
+        Replay::<3>::new(5, config, vec![PlayCard(Skull), PlayCard(Flower)])
+            .unwrap();
+    let mut file = Vec::new();
+    replay.write_to(&mut file).unwrap();
+    let read = Replay::<3>::read_from(file.as_slice()).unwrap();
+    assert_eq!(read.game().config(), &config, "Rules changed when read");
+}
+
+#[test]
+fn seating_round_trip() {
+    let names = ["Alice", "Bob", "Carol", "Dan"];
+    let mut table = Table::with_names(Game::<4>::with_seed(3), names)
+        .unwrap()
+        .shuffle_seats()
+        .unwrap();
+    table.respond(PlayCard(Flower)).unwrap();
+    let replay = Replay::from_table(&table).unwrap();
+    let seating = table
+        .players()
+        .iter()
+        .map(|player| player.id())
+        .collect::<Vec<_>>();
+    assert_ne!(
+        seating,
+        (0..4).map(PlayerId).collect::<Vec<_>>(),
+        "Test seed didn't move anyone"
+    );
+    assert_eq!(replay.seating(), seating.as_slice());
+
+    let mut file = Vec::new();
+    replay.write_to(&mut file).unwrap();
+    let read = Replay::<4>::read_from(file.as_slice()).unwrap();
+    assert_eq!(
+        read.seating(),
+        seating.as_slice(),
+        "Seating changed when read"
+    );
+    assert_eq!(read.responses(), replay.responses());
+}
+
+#[test]
+fn invalid_replays() {
+    const HEADER: &str = "skull-replay 1\nplayers 3\nseed 5\ndiscard chosen\n\
+                          points-to-win 2\nstarting-hand FFFS\n\
+                          flip-own-first true\nfirst-player 0\n\
+                          seating 0 1 2\n";
+    let read = |text: &str| Replay::<3>::read_from(text.as_bytes());
+    assert!(matches!(
+        read(&HEADER.replace("skull-replay 1", "skull-replay 2")),
+        Err(ReplayError::UnsupportedVersion(2))
+    ));
+    assert!(matches!(
+        read(&HEADER.replace("players 3", "players 4")),
+        Err(ReplayError::WrongPlayerCount {
+            expected: 3,
+            found: 4
+        })
+    ));
+    assert!(matches!(
+        read("skull-replay 1\nplayers 3\nseed 5\n"),
+        Err(ReplayError::MissingHeader("discard"))
+    ));
+    assert!(matches!(
+        read(&HEADER.replace("points-to-win 2", "points-to-win 0")),
+        Err(ReplayError::InvalidConfig(_))
+    ));
+    for seating in ["seating 0 1 1", "seating 0 1", "seating"] {
+        assert!(
+            matches!(
+                read(&HEADER.replace("seating 0 1 2", seating)),
+                Err(ReplayError::MissingHeader("seating"))
+            ),
+            "{:?} accepted",
+            seating
+        );
+    }
+    assert!(matches!(
+        read(&format!("{}F Q\n", HEADER)),
+        Err(ReplayError::InvalidToken(_))
+    ));
+    assert!(matches!(
+        read(&format!("{}F P\n", HEADER)),
+        Err(ReplayError::InvalidResponse {
+            position: 1,
+            response: Pass,
+            error: ResponseError::IncorrectInputType { .. },
+        })
+    ));
+    assert!(
+        read(HEADER).is_ok(),
+        "Replay with no responses should be read"
+    );
+}
+
+#[test]
+fn not_replayable() {
+    let mut game: Game<3> = Game::new();
+    respond(&mut game, PlayCard(Flower));
+    game.reseed(7);
+    assert!(!game.is_replayable());
+    assert!(Replay::from_game(&game).is_none());
+}
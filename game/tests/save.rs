@@ -0,0 +1,257 @@
+#![cfg(feature = "save")]
+
+use game::Card::*;
+use game::Response::*;
+use game::*;
+
+use serde_json::{json, Value};
+
+fn game_in_progress() -> Game<3> {
+    let mut game = Game::with_seed(11);
+    for _ in 0..3 {
+        game.respond(PlayCard(Flower)).unwrap();
+    }
+    game.respond(Bid(2)).unwrap();
+    game
+}
+
+fn save(game: &Game<3>) -> Value {
+    let mut file = Vec::new();
+    game.save_to(&mut file).expect("Game should have saved");
+    serde_json::from_slice(&file).unwrap()
+}
+
+fn load<const N: usize>(file: &Value) -> Result<Game<N>, SaveError> {
+    Game::load_from(file.to_string().as_bytes())
+}
+
+#[test]
+fn round_trip() {
+    let mut game = game_in_progress();
+    let file = save(&game);
+    assert_eq!(file["version"], json!(SAVE_VERSION));
+    assert_eq!(file["players"], json!(3));
+
+    let mut loaded: Game<3> = load(&file).expect("Game should have loaded");
+    assert_eq!(loaded.state(), game.state(), "State changed when loaded");
+    assert_eq!(loaded.seed(), game.seed(), "Seed changed when loaded");
+    assert_eq!(
+        loaded.what_next().unwrap(),
+        game.what_next().unwrap(),
+        "Pending event lost when loaded"
+    );
+}
+
+// Saves a game with a challenger who has just flipped a skull, and checks it
+// loads and plays on as it would have
+fn skull_flip_round_trip<const N: usize>(mut game: Game<N>) {
+    let mut file = Vec::new();
+    game.save_to(&mut file).expect("Game should have saved");
+    let mut loaded =
+        Game::<N>::load_from(file.as_slice()).expect("Game should have loaded");
+    assert_eq!(loaded.state(), game.state(), "State changed when loaded");
+    assert_eq!(loaded.hands(), game.hands(), "Hands changed when loaded");
+    let event = game.what_next().unwrap();
+    assert!(
+        matches!(event, Event::ChallengerChoseSkull { .. }),
+        "Skull flip not pending"
+    );
+    assert_eq!(
+        loaded.what_next().unwrap(),
+        event,
+        "Pending event lost when loaded"
+    );
+    assert_eq!(
+        loaded.what_next().unwrap(),
+        game.what_next().unwrap(),
+        "Loaded game played on differently"
+    );
+    assert_eq!(
+        loaded.hands(),
+        game.hands(),
+        "Loaded game played on differently"
+    );
+}
+
+#[test]
+fn skull_flipped_round_trip() {
+    // Challenger flips someone else's skull, and chooses a discard
+    let mut game = Game::<3>::with_seed(5);
+    for card in [Flower, Skull, Flower] {
+        game.respond(PlayCard(card)).unwrap();
+    }
+    game.respond(Bid(3)).unwrap();
+    game.what_next().unwrap();
+    game.respond(Flip(1, 0)).unwrap();
+    skull_flip_round_trip(game);
+
+    // Challenger flips their own skull by hand
+    let mut game = Game::<3>::with_config(GameConfig {
+        challenger_flips_own_first: false,
+        ..GameConfig::default()
+    });
+    for card in [Skull, Flower, Flower] {
+        game.respond(PlayCard(card)).unwrap();
+    }
+    game.respond(Bid(3)).unwrap();
+    game.what_next().unwrap();
+    game.respond(Flip(0, 0)).unwrap();
+    skull_flip_round_trip(game);
+
+    // Challenger's own skull is flipped for them after they played their
+    // whole hand, and they lose a card at random
+    let mut game = Game::<2>::with_config(GameConfig {
+        discard_mode: DiscardMode::Random,
+        ..GameConfig::default()
+    });
+    for card in [Skull, Flower, Flower, Flower, Flower, Flower, Flower] {
+        game.respond(PlayCard(card)).unwrap();
+    }
+    game.respond(Bid(1)).unwrap();
+    game.what_next().unwrap();
+    game.respond(Bid(7)).unwrap();
+    game.what_next().unwrap();
+    skull_flip_round_trip(game);
+}
+
+#[test]
+fn table_round_trip() {
+    let names = ["Alice", "Bob", "Carol"];
+    let table = Table::with_names(Game::with_seed(2), names)
+        .unwrap()
+        .shuffle_seats()
+        .unwrap();
+    let mut file = Vec::new();
+    table.save_to(&mut file).expect("Table should have saved");
+    let loaded = Table::<3>::load_from(file.as_slice())
+        .expect("Table should have loaded");
+    assert_eq!(
+        loaded.players(),
+        table.players(),
+        "Seating changed when loaded"
+    );
+    assert_eq!(loaded.game().state(), table.game().state());
+    assert!(
+        Game::<3>::load_from(file.as_slice()).is_ok(),
+        "Table's save file should load as a game"
+    );
+
+    let mut file: Value = serde_json::from_slice(&file).unwrap();
+    file["seats"][0]["id"] = file["seats"][1]["id"].clone();
+    let err = Table::<3>::load_from(file.to_string().as_bytes()).unwrap_err();
+    assert!(
+        matches!(err, SaveError::InvalidPlayers(TableError::DuplicateId(_))),
+        "Expected invalid players error, got {:?}",
+        err
+    );
+
+    let err =
+        Table::<3>::load_from(save(&game_in_progress()).to_string().as_bytes())
+            .unwrap_err();
+    assert!(
+        matches!(err, SaveError::MissingField("seats")),
+        "Expected missing seats error, got {:?}",
+        err
+    );
+}
+
+#[test]
+fn unsupported_version() {
+    let mut file = save(&game_in_progress());
+    file["version"] = json!(SAVE_VERSION + 1);
+    let err = load::<3>(&file).unwrap_err();
+    assert!(
+        matches!(err, SaveError::UnsupportedVersion(v) if v == SAVE_VERSION + 1),
+        "Expected unsupported version error, got {:?}",
+        err
+    );
+}
+
+#[test]
+fn wrong_player_count() {
+    let file = save(&game_in_progress());
+    let err = load::<4>(&file).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            SaveError::WrongPlayerCount {
+                expected: 4,
+                found: 3
+            }
+        ),
+        "Expected wrong player count error, got {:?}",
+        err
+    );
+}
+
+#[test]
+fn tampered_game() {
+    let mut file = save(&game_in_progress());
+    file["game"]["scores"] = json!([3, 0, 0]);
+    let err = load::<3>(&file).unwrap_err();
+    assert!(
+        matches!(err, SaveError::InvalidGame(_)),
+        "Expected invalid game error, got {:?}",
+        err
+    );
+
+    // Two skulls make a valid hand, but not one the rules allow
+    let mut file = save(&game_in_progress());
+    file["game"]["player_hands"][0] = json!(["Skull", "Skull"]);
+    let err = load::<3>(&file).unwrap_err();
+    assert!(
+        matches!(err, SaveError::InvalidGame(_)),
+        "Expected invalid game error, got {:?}",
+        err
+    );
+
+    let mut file = save(&game_in_progress());
+    file["game"]["player_hands"][0] = json!(vec!["Flower"; MAX_HAND_SIZE + 1]);
+    let err = load::<3>(&file).unwrap_err();
+    assert!(
+        matches!(err, SaveError::Format(_)),
+        "Expected format error, got {:?}",
+        err
+    );
+}
+
+#[test]
+fn tampered_skull_flip() {
+    let mut game = Game::<3>::with_seed(5);
+    for card in [Flower, Skull, Flower] {
+        game.respond(PlayCard(card)).unwrap();
+    }
+    game.respond(Bid(3)).unwrap();
+    game.what_next().unwrap();
+    game.respond(Flip(1, 0)).unwrap();
+    let file = save(&game);
+    assert!(
+        load::<3>(&file).is_ok(),
+        "Untampered game should have loaded"
+    );
+
+    for (challenger, skull_player) in [(7, 1), (0, 7), (2, 1)] {
+        let mut file = file.clone();
+        file["game"]["pending_event"]["ChallengerChoseSkull"] = json!({
+            "challenger": challenger,
+            "skull_player": skull_player,
+        });
+        let err = load::<3>(&file).unwrap_err();
+        assert!(
+            matches!(err, SaveError::InvalidGame(_)),
+            "Expected invalid game error for {:?}, got {:?}",
+            (challenger, skull_player),
+            err
+        );
+    }
+}
+
+#[test]
+fn not_a_save_file() {
+    let err = Game::<3>::load_from("not json".as_bytes()).unwrap_err();
+    assert!(
+        matches!(err, SaveError::Format(_)),
+        "Expected format error, got {:?}",
+        err
+    );
+}
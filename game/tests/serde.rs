@@ -0,0 +1,265 @@
+#![cfg(feature = "serde")]
+
+use game::Card::*;
+use game::Response::*;
+use game::*;
+
+use serde_json::json;
+use std::convert::TryFrom;
+
+#[test]
+fn hand_representation() {
+    let hand = Hand::try_from([Flower, Skull, Flower]).unwrap();
+    let value = serde_json::to_value(hand).unwrap();
+    assert_eq!(
+        value,
+        json!(["Flower", "Flower", "Skull"]),
+        "Hand not represented as list of cards"
+    );
+    assert_eq!(
+        serde_json::from_value::<Hand>(value).unwrap(),
+        hand,
+        "Hand changed during round trip"
+    );
+
+    let too_many_cards = json!(vec!["Skull"; MAX_HAND_SIZE + 1]);
+    assert!(
+        serde_json::from_value::<Hand>(too_many_cards).is_err(),
+        "Invalid hand deserialized"
+    );
+}
+
+#[test]
+fn response_representation() {
+    assert_eq!(
+        serde_json::to_value(Flip(1, 2)).unwrap(),
+        json!({"Flip": [1, 2]})
+    );
+    assert_eq!(serde_json::to_value(Pass).unwrap(), json!("Pass"));
+    assert_eq!(
+        serde_json::to_value(PlayCard(Skull)).unwrap(),
+        json!({"PlayCard": "Skull"})
+    );
+}
+
+#[test]
+fn state_representation() {
+    let state: State<3> = State::Bidding {
+        current_bidder: 0,
+        highest_bid: 1,
+        highest_bidder: 2,
+        max_bid: 3,
+        passed: [false, true, false],
+    };
+    let value = serde_json::to_value(&state).unwrap();
+    assert_eq!(
+        value,
+        json!({"Bidding": {
+            "current_bidder": 0,
+            "highest_bid": 1,
+            "highest_bidder": 2,
+            "max_bid": 3,
+            "passed": [false, true, false],
+        }})
+    );
+    assert_eq!(
+        serde_json::from_value::<State<3>>(value).unwrap(),
+        state,
+        "State changed during round trip"
+    );
+}
+
+#[test]
+fn game_round_trip() {
+    let mut game: Game<3> = Game::with_seed(7);
+    for _ in 0..3 {
+        game.respond(PlayCard(Flower)).unwrap();
+    }
+    game.respond(Bid(2)).unwrap();
+
+    let json = serde_json::to_string(&game).unwrap();
+    let mut copy: Game<3> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        copy.state(),
+        game.state(),
+        "State changed during round trip"
+    );
+    assert_eq!(
+        copy.hands(),
+        game.hands(),
+        "Hands changed during round trip"
+    );
+    assert_eq!(
+        copy.cards_played(),
+        game.cards_played(),
+        "Cards played changed during round trip"
+    );
+    assert_eq!(
+        copy.history(),
+        game.history(),
+        "History changed during round trip"
+    );
+    assert_eq!(
+        copy.what_next().unwrap(),
+        game.what_next().unwrap(),
+        "Pending event changed during round trip"
+    );
+}
+
+#[test]
+fn inconsistent_game() {
+    let mut value = serde_json::to_value(Game::<3>::with_seed(7)).unwrap();
+    value["player_hands"][0] = json!(["Flower"]);
+    value["cards_played"][0] = json!(["Skull"]);
+    let mut game: Game<3> = serde_json::from_value(value.clone()).unwrap();
+    assert!(
+        matches!(game.check_invariants(), Err(EngineError::InvalidState(_))),
+        "Inconsistent game passed checks"
+    );
+    assert!(
+        matches!(
+            game.respond(PlayCard(Flower)),
+            Err(ResponseError::Engine(EngineError::InvalidState(_)))
+        ),
+        "Response to inconsistent game wasn't an engine error"
+    );
+    assert_eq!(game.legal_responses(), vec![]);
+
+    value["pending_event"] =
+        json!({"Input": {"player": 0, "input": "PlayCard"}});
+    let mut game: Game<3> = serde_json::from_value(value).unwrap();
+    assert!(
+        matches!(game.what_next(), Err(EngineError::InvalidState(_))),
+        "Pending input event wasn't an engine error"
+    );
+}
+
+#[test]
+fn unchanged_after_engine_error() {
+    let mut value = serde_json::to_value(Game::<3>::with_seed(7)).unwrap();
+    value["player_hands"] = json!([[], [], []]);
+    value["cards_played"] = json!([["Flower"], ["Flower"], ["Flower"]]);
+    let mut game: Game<3> = serde_json::from_value(value).unwrap();
+    game.set_undo_depth(1);
+    let state = game.state().clone();
+
+    // Bidding starts before the next bidder is looked for, and there isn't one
+    assert_eq!(
+        game.respond(Bid(1)),
+        Err(ResponseError::Engine(EngineError::AllPlayersOut))
+    );
+    assert_eq!(game.state(), &state, "Game changed by failed response");
+    assert_eq!(game.history(), &[], "Failed response recorded");
+    assert_eq!(game.undo(), None, "Failed response can be undone");
+    assert_eq!(
+        game.what_next().unwrap(),
+        Event::Input {
+            player: 0,
+            input: InputType::StartBid,
+        },
+        "Event pending after failed response"
+    );
+}
+
+#[test]
+fn event_unchanged_after_engine_error() {
+    let mut value = serde_json::to_value(Game::<3>::with_seed(7)).unwrap();
+    value["config"]["discard_mode"] = json!("Random");
+    value["player_hands"] = json!([[], [], []]);
+    value["cards_played"] = json!([["Skull"], ["Flower"], ["Flower"]]);
+    value["state"] = json!({"Challenging": {
+        "challenger": 0,
+        "target": 2,
+        "flipped": [[0], [], []],
+    }});
+    let event = Event::ChallengerChoseSkull {
+        challenger: 0,
+        skull_player: 0,
+    };
+    value["pending_event"] = serde_json::to_value(Some(event)).unwrap();
+    let mut game: Game<3> = serde_json::from_value(value).unwrap();
+    let state = game.state().clone();
+
+    // The challenge is lost before the next player is looked for, and there
+    // isn't one
+    assert_eq!(game.what_next(), Err(EngineError::AllPlayersOut));
+    assert_eq!(game.state(), &state, "Game changed by failed event");
+    assert_eq!(game.round(), 0, "Round ended by failed event");
+    assert_eq!(
+        game.cards_played(),
+        vec![&[Skull][..], &[Flower], &[Flower]],
+        "Cards played reset by failed event"
+    );
+    assert_eq!(game.history(), &[], "Failed event recorded");
+    assert_eq!(
+        game.what_next(),
+        Err(EngineError::AllPlayersOut),
+        "Event no longer pending after failing"
+    );
+}
+
+#[test]
+fn every_violation_reported() {
+    let game: Game<3> = Game::with_seed(7);
+    assert_eq!(game.validate_state(), Ok(()));
+
+    let mut value = serde_json::to_value(game).unwrap();
+    value["scores"] = json!([5, 0, 0]);
+    value["player_hands"][1] = json!(["Skull", "Skull"]);
+    value["state"] = json!({"Playing": {"current_player": 7}});
+    let game: Game<3> = serde_json::from_value(value).unwrap();
+    let violations = game.validate_state().unwrap_err();
+    assert!(
+        matches!(violations[0], InvariantViolation::Scores(_)),
+        "Score above points to win not reported"
+    );
+    assert!(
+        matches!(violations[1], InvariantViolation::Hands(_)),
+        "Hand not from starting hand not reported"
+    );
+    assert!(
+        matches!(violations.last(), Some(InvariantViolation::State(_))),
+        "Out of range player not reported"
+    );
+    assert_eq!(
+        game.check_invariants(),
+        Err(EngineError::InvalidState(violations[0].description())),
+        "First violation not reported by check_invariants"
+    );
+}
+
+#[test]
+fn table_checked_when_deserialized() {
+    let table =
+        Table::with_names(Game::<3>::with_seed(7), ["Alice", "Bob", "Carol"])
+            .unwrap();
+    let value = serde_json::to_value(&table).unwrap();
+    let copy: Table<3> = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(copy.players(), table.players());
+
+    let invalid = |change: fn(&mut serde_json::Value)| {
+        let mut value = value.clone();
+        change(&mut value);
+        serde_json::from_value::<Table<3>>(value)
+            .expect_err("Invalid table deserialized")
+            .to_string()
+    };
+    assert_eq!(
+        invalid(|value| {
+            value["players"].as_array_mut().unwrap().pop();
+        }),
+        TableError::WrongPlayerCount {
+            expected: 3,
+            found: 2
+        }
+        .to_string()
+    );
+    assert_eq!(
+        invalid(|value| value["players"][2]["id"] = json!(0)),
+        TableError::DuplicateId(PlayerId(0)).to_string()
+    );
+    assert_eq!(
+        invalid(|value| value["players"][1]["seat"] = json!(5)),
+        TableError::WrongSeat(PlayerId(1)).to_string()
+    );
+}
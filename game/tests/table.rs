@@ -0,0 +1,137 @@
+use game::Card::*;
+use game::Response::*;
+use game::*;
+
+#[test]
+fn players_seated_in_order() {
+    let table = Table::new(
+        Game::<3>::new(),
+        [
+            (PlayerId(7), "Alice"),
+            (PlayerId(3), "Bob"),
+            (PlayerId(9), "Carol"),
+        ],
+    )
+    .unwrap();
+    let bob = table.player(1);
+    assert_eq!(bob.name(), "Bob");
+    assert_eq!(bob.id(), PlayerId(3));
+    assert_eq!(bob.seat(), 1);
+    let carol = table.player_by_id(PlayerId(9)).unwrap();
+    assert_eq!(carol.seat(), 2, "Player found by ID is in the wrong seat");
+    assert!(table.player_by_id(PlayerId(0)).is_none());
+}
+
+#[test]
+fn invalid_players() {
+    assert_eq!(
+        Table::with_names(Game::<3>::new(), ["Alice", "Bob"]).unwrap_err(),
+        TableError::WrongPlayerCount {
+            expected: 3,
+            found: 2
+        }
+    );
+    assert_eq!(
+        Table::new(
+            Game::<2>::new(),
+            [(PlayerId(1), "Alice"), (PlayerId(1), "Bob")]
+        )
+        .unwrap_err(),
+        TableError::DuplicateId(PlayerId(1))
+    );
+}
+
+#[test]
+fn default_names() {
+    let table = Table::with_default_names(Game::<4>::new());
+    let names = table
+        .players()
+        .iter()
+        .map(|player| (player.id(), player.name()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            (PlayerId(0), "Player 1"),
+            (PlayerId(1), "Player 2"),
+            (PlayerId(2), "Player 3"),
+            (PlayerId(3), "Player 4"),
+        ]
+    );
+}
+
+#[test]
+fn shuffled_seats() {
+    let seat = |seed| {
+        let names = ["Alice", "Bob", "Carol", "Dan", "Erin", "Fred"];
+        let table = Table::with_names(Game::<6>::with_seed(seed), names)
+            .unwrap()
+            .shuffle_seats()
+            .unwrap();
+        assert_eq!(
+            table.game().state(),
+            Game::<6>::with_seed(seed).state(),
+            "Shuffling seats changed the game"
+        );
+        table
+            .players()
+            .iter()
+            .enumerate()
+            .map(|(seat, player)| {
+                assert_eq!(player.seat(), seat, "Player's seat not updated");
+                player.id()
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(seat(1), seat(1), "Same seed seated players differently");
+    let mut ids = seat(1);
+    ids.sort();
+    assert_eq!(ids, (0..6).map(PlayerId).collect::<Vec<_>>());
+    assert!(
+        (0..10).any(|seed| seat(seed) != ids),
+        "Players were never moved"
+    );
+}
+
+#[test]
+fn seats_fixed_once_started() {
+    let mut table = Table::with_default_names(Game::<3>::with_seed(1));
+    table.respond(PlayCard(Flower)).unwrap();
+    assert_eq!(table.shuffle_seats().unwrap_err(), TableError::GameStarted);
+}
+
+#[test]
+fn described_with_names() {
+    let mut table =
+        Table::with_names(Game::<2>::with_seed(0), ["Alice", "Bob"]).unwrap();
+    table.respond(PlayCard(Flower)).unwrap();
+    table.respond(PlayCard(Skull)).unwrap();
+    table.respond(Bid(2)).unwrap();
+    let event = table.what_next().unwrap();
+    assert_eq!(table.named(event).to_string(), "The challenge has started");
+    assert_eq!(
+        table.named(table.game().state()).to_string(),
+        "Alice is trying to flip 2 flowers, and has flipped 1"
+    );
+    table.respond(Flip(1, 0)).unwrap();
+    let event = table.what_next().unwrap();
+    assert_eq!(table.named(event).to_string(), "Alice flipped Bob's skull!");
+
+    let log = table
+        .game()
+        .history()
+        .iter()
+        .map(|entry| table.named(*entry).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        log,
+        [
+            "Alice played a card",
+            "Bob played a card",
+            "Alice bid 2",
+            "The challenge has started",
+            "Alice flipped Bob's card #1",
+            "Alice flipped Bob's skull!",
+        ]
+    );
+}
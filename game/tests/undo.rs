@@ -0,0 +1,102 @@
+use game::Card::*;
+use game::Event::*;
+use game::HistoryItem;
+use game::Response::*;
+use game::*;
+
+fn game_with_undo(depth: usize) -> Game<3> {
+    let mut game = Game::with_seed(3);
+    game.set_undo_depth(depth);
+    game
+}
+
+#[test]
+fn disabled_by_default() {
+    let mut game: Game<3> = Game::new();
+    assert_eq!(game.undo_depth(), 0);
+    game.respond(PlayCard(Flower)).unwrap();
+    assert_eq!(game.undo(), None, "Undo should be disabled by default");
+    assert_eq!(game.cards_played()[0], &[Flower]);
+}
+
+#[test]
+fn undo_card() {
+    let mut game = game_with_undo(4);
+    game.respond(PlayCard(Flower)).unwrap();
+    let before = game.clone();
+    game.respond(PlayCard(Skull)).unwrap();
+
+    assert_eq!(game.undo(), Some(PlayCard(Skull)));
+    assert_eq!(game.state(), before.state(), "State wasn't restored");
+    assert_eq!(
+        game.cards_played(),
+        before.cards_played(),
+        "Cards played weren't restored"
+    );
+    assert_eq!(game.history(), before.history(), "History wasn't restored");
+    // Player 1 can now play a different card
+    game.respond(PlayCard(Flower)).unwrap();
+    assert_eq!(game.cards_played()[1], &[Flower]);
+}
+
+#[test]
+fn undo_bid() {
+    let mut game = game_with_undo(4);
+    for _ in 0..3 {
+        game.respond(PlayCard(Flower)).unwrap();
+    }
+    game.respond(Bid(1)).unwrap();
+    assert_eq!(game.what_next().unwrap(), BidStarted);
+    assert_eq!(game.undo(), Some(Bid(1)), "Bid should have been undone");
+    assert_eq!(
+        game.what_next().unwrap(),
+        Input {
+            player: 0,
+            input: InputType::PlayCardOrStartBid
+        },
+        "Bid wasn't undone"
+    );
+    assert!(
+        game.history()
+            .iter()
+            .all(|entry| entry.item != HistoryItem::Event(BidStarted)),
+        "Undone event left in history"
+    );
+}
+
+#[test]
+fn depth_limited() {
+    let mut game = game_with_undo(2);
+    for _ in 0..3 {
+        game.respond(PlayCard(Flower)).unwrap();
+    }
+    assert_eq!(game.undo_count(), 2);
+    assert!(game.undo().is_some());
+    assert!(game.undo().is_some());
+    assert_eq!(game.undo(), None, "Undid more than the undo depth");
+    assert_eq!(game.cards_played()[0], &[Flower]);
+
+    game.respond(PlayCard(Flower)).unwrap();
+    game.set_undo_depth(0);
+    assert_eq!(game.undo(), None, "Lowering depth didn't forget responses");
+}
+
+#[test]
+fn cleared_by_flip() {
+    let mut game = game_with_undo(10);
+    for _ in 0..3 {
+        game.respond(PlayCard(Flower)).unwrap();
+    }
+    game.respond(Bid(2)).unwrap();
+    game.what_next().unwrap();
+    game.respond(Pass).unwrap();
+    game.respond(Pass).unwrap();
+    assert_eq!(game.undo_count(), 6);
+    // Challenger's own card is flipped
+    assert_eq!(game.what_next().unwrap(), ChallengeStarted);
+    assert_eq!(game.undo(), None, "Undid past a flipped card");
+
+    game.respond(Flip(1, 0)).unwrap();
+    assert_eq!(game.undo(), None, "Undid a flip");
+    assert_eq!(game.what_next().unwrap(), ChallengeWon(0));
+}
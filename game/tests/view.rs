@@ -0,0 +1,78 @@
+use game::Card::*;
+use game::*;
+
+use std::convert::TryFrom;
+
+#[test]
+fn own_cards_visible() {
+    let game = Game::create_from(
+        [0; 3],
+        [Hand::new(), Hand::new(), Hand::try_from([Flower]).unwrap()],
+        [fvec![Skull], fvec![Flower], fvec![Flower]],
+        State::Playing { current_player: 0 },
+        None,
+    );
+    let view = game.view_for(0);
+    assert_eq!(view.player(), 0);
+    assert_eq!(view.hand(), &Hand::new(), "Player's own hand not visible");
+    assert_eq!(
+        view.cards_played(),
+        &[Skull],
+        "Player's own cards played not visible"
+    );
+    assert_eq!(view.scores(), game.scores(), "Scores don't match game");
+}
+
+#[test]
+fn other_players_hidden() {
+    let game = Game::create_from(
+        [0; 3],
+        [Hand::new(), Hand::new(), Hand::try_from([Flower]).unwrap()],
+        [fvec![Skull], fvec![Flower, Skull], fvec![Flower]],
+        State::Playing { current_player: 2 },
+        None,
+    );
+    let view = game.view_for(2);
+    assert_eq!(
+        view.hand_counts(),
+        &[4, 4, 1],
+        "Incorrect number of cards in players' hands"
+    );
+    assert_eq!(
+        view.stack_heights(),
+        &[1, 2, 1],
+        "Incorrect number of cards on the table"
+    );
+    assert!(
+        (0..3).all(|player| view.revealed(player).is_empty()),
+        "Cards revealed when not challenging"
+    );
+}
+
+#[test]
+fn flipped_cards_revealed() {
+    let game = Game::create_from(
+        [0; 3],
+        [Hand::new(); 3],
+        [fvec![Flower; 2], fvec![Skull, Flower], fvec![Flower, Skull]],
+        State::Challenging {
+            challenger: 0,
+            target: 5,
+            flipped: [fvec![0, 1], fvec![1], fvec![]],
+        },
+        None,
+    );
+    let view = game.view_for(2);
+    assert_eq!(
+        view.revealed(0),
+        &[(0, Flower), (1, Flower)],
+        "Challenger's flipped cards not revealed"
+    );
+    assert_eq!(
+        view.revealed(1),
+        &[(1, Flower)],
+        "Flipped card not revealed"
+    );
+    assert!(view.revealed(2).is_empty(), "Unflipped cards revealed");
+    assert_eq!(view.cards_played(), &[Flower, Skull]);
+}
@@ -0,0 +1,207 @@
+use crate::session::Seats;
+use game::*;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+/// Play Skull, the game of bluffing, in the terminal
+#[derive(Debug, Parser)]
+#[command(version)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Play a hot-seat game, with everyone taking turns on this terminal
+    Play(PlayArgs),
+    /// Show everything that happened in a recorded game
+    Replay(ReplayArgs),
+    /// Play bots against each other and report how often each player wins
+    Simulate(SimulateArgs),
+    /// Host a game for players connecting over TCP (e.g. with netcat)
+    Serve(ServeArgs),
+}
+
+#[derive(Debug, clap::Args)]
+pub struct PlayArgs {
+    #[command(flatten)]
+    pub game: GameArgs,
+    #[command(flatten)]
+    pub seats: SeatArgs,
+    /// Resume the game saved in this file, instead of starting a new one
+    ///
+    /// The rules and seed are taken from the save file
+    #[arg(long)]
+    pub load: Option<PathBuf>,
+    /// Use a full-screen interface, played with the arrow keys
+    #[arg(long)]
+    pub tui: bool,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct ReplayArgs {
+    /// The replay file, as written by `--record`
+    pub path: PathBuf,
+    /// The players' names, in seat order and separated by commas
+    #[arg(long, value_delimiter = ',')]
+    pub names: Vec<String>,
+    /// Wait for Enter after each response
+    #[arg(long)]
+    pub step: bool,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct SimulateArgs {
+    #[command(flatten)]
+    pub game: GameArgs,
+    /// The number of games to play
+    #[arg(short = 'n', long, default_value_t = 100)]
+    pub games: usize,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct ServeArgs {
+    #[command(flatten)]
+    pub game: GameArgs,
+    #[command(flatten)]
+    pub seats: SeatArgs,
+    /// The address to listen for players on
+    #[arg(long, default_value = "127.0.0.1:4000")]
+    pub address: String,
+}
+
+// Options for creating a new game
+#[derive(Debug, clap::Args)]
+pub struct GameArgs {
+    /// The number of players
+    #[arg(
+        short,
+        long,
+        default_value_t = 3,
+        value_parser = clap::value_parser!(u8).range(MIN_PLAYERS as i64..=MAX_PLAYERS as i64),
+    )]
+    pub players: u8,
+    /// The players' names, in seat order and separated by commas
+    #[arg(long, value_delimiter = ',')]
+    pub names: Vec<String>,
+    /// Seed for the game's random number generator, for a reproducible game
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// The number of successful challenges needed to win
+    #[arg(long, default_value_t = 2)]
+    pub points_to_win: u8,
+    /// The hand everyone starts with, one letter per card (F or S)
+    #[arg(long, default_value = "FFFS", value_parser = parse_hand)]
+    pub starting_hand: Hand,
+    /// How the card a challenger loses is chosen
+    #[arg(long, value_enum, default_value_t = Discard::Chosen)]
+    pub discard: Discard,
+    /// Let the challenger flip cards in any order, instead of their own
+    /// first
+    #[arg(long)]
+    pub flip_any_order: bool,
+    /// Who plays the first card: a seat (counting from 1), or "random"
+    #[arg(long, default_value = "1", value_parser = parse_first_player)]
+    pub first_player: FirstPlayer,
+    /// Seat the players in a random order, instead of the order named
+    #[arg(long)]
+    pub shuffle_seats: bool,
+}
+
+impl GameArgs {
+    pub fn player_count(&self) -> usize {
+        self.players as usize
+    }
+
+    pub fn config(&self) -> GameConfig {
+        GameConfig {
+            points_to_win: self.points_to_win,
+            starting_hand: self.starting_hand,
+            challenger_flips_own_first: !self.flip_any_order,
+            discard_mode: match self.discard {
+                Discard::Chosen => DiscardMode::Chosen,
+                Discard::Random => DiscardMode::Random,
+            },
+            first_player: self.first_player,
+        }
+    }
+
+    pub fn new_game<const N: usize>(&self) -> Result<Game<N>, String> {
+        let config = self.config();
+        config.check_valid(N)?;
+        let mut game = Game::with_config(config);
+        if let Some(seed) = self.seed {
+            game.reseed(seed);
+        }
+        Ok(game)
+    }
+}
+
+// Options for who sits where, when a game has more than one player on a
+// terminal
+#[derive(Debug, clap::Args)]
+pub struct SeatArgs {
+    /// Seats (counting from 1, separated by commas) played by bots
+    #[arg(long, value_delimiter = ',')]
+    pub bots: Vec<usize>,
+    /// Save the game to this file after every turn, to resume with `--load`
+    #[arg(long)]
+    pub save: Option<PathBuf>,
+    /// Write a replay of the game to this file when it ends
+    #[arg(long)]
+    pub record: Option<PathBuf>,
+}
+
+impl SeatArgs {
+    pub fn seats(&self, player_count: usize) -> Result<Seats, String> {
+        let bots = self
+            .bots
+            .iter()
+            .map(|seat| match seat.checked_sub(1) {
+                Some(player) if player < player_count => Ok(player),
+                _ => Err(format!(
+                    "There's no seat {} with {} players",
+                    seat, player_count
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Seats {
+            bots,
+            save: self.save.clone(),
+            record: self.record.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+pub enum Discard {
+    /// The owner of the flipped skull chooses
+    Chosen,
+    /// A card is chosen at random
+    Random,
+}
+
+fn parse_hand(letters: &str) -> Result<Hand, String> {
+    let cards = letters
+        .chars()
+        .map(|letter| match letter.to_ascii_uppercase() {
+            'F' => Ok(Card::Flower),
+            'S' => Ok(Card::Skull),
+            _ => Err(format!("{:?} isn't a card, use F or S", letter)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Hand::try_from(cards.as_slice()).map_err(|err| err.to_string())
+}
+
+fn parse_first_player(value: &str) -> Result<FirstPlayer, String> {
+    match value {
+        "random" => Ok(FirstPlayer::Random),
+        seat => match seat.parse::<usize>() {
+            Ok(seat) if seat > 0 => Ok(FirstPlayer::Seat(seat - 1)),
+            _ => Err(String::from("Use a seat, counting from 1, or random")),
+        },
+    }
+}
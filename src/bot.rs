@@ -0,0 +1,26 @@
+use game::*;
+
+use nanorand::{Rng, WyRand};
+
+// A player that picks any response the game would accept, at random
+//
+// Only the legal responses are looked at, so a bot never sees anything its
+// seat isn't allowed to know
+#[derive(Debug, Clone)]
+pub struct Bot {
+    rng: WyRand,
+}
+
+impl Bot {
+    pub fn new(seed: u64) -> Self {
+        Bot {
+            rng: WyRand::new_seed(seed),
+        }
+    }
+
+    pub fn choose<const N: usize>(&mut self, game: &Game<N>) -> Response {
+        let responses = game.legal_responses();
+        assert!(!responses.is_empty(), "Bot asked to respond out of turn");
+        responses[self.rng.generate_range(0..responses.len())]
+    }
+}
@@ -0,0 +1,294 @@
+use crate::session::{Players, Result};
+use game::*;
+
+use std::io::{BufRead, Write};
+
+// ANSI escape code to clear the terminal and move the cursor to the top left
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+// A line-based terminal, for one player or shared by many
+pub struct Terminal<R: BufRead, W: Write> {
+    pub input: R,
+    pub output: W,
+}
+
+impl<R: BufRead, W: Write> Terminal<R, W> {
+    // Reads a trimmed, lowercase line, failing if there's no more input
+    pub fn read_line(&mut self) -> Result<String> {
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            return Err("Input closed".into());
+        }
+        Ok(line.trim().to_lowercase())
+    }
+
+    pub fn prompt(&mut self, message: &str) -> Result<String> {
+        write!(self.output, "{} ", message)?;
+        self.output.flush()?;
+        self.read_line()
+    }
+
+    pub fn say(&mut self, message: &str) -> Result<()> {
+        writeln!(self.output, "{}", message)?;
+        self.output.flush()?;
+        Ok(())
+    }
+
+    pub fn clear(&mut self) -> Result<()> {
+        write!(self.output, "{}", CLEAR_SCREEN)?;
+        Ok(())
+    }
+
+    // Shows a player everything they're allowed to know about the game
+    pub fn show_view<const N: usize>(
+        &mut self,
+        table: &Table<N>,
+        player: usize,
+    ) -> Result<()> {
+        let view = table.game().view_for(player);
+        let name = |player| table.player(player).name();
+        let scores = view
+            .scores()
+            .iter()
+            .enumerate()
+            .map(|(player, score)| format!("{}: {}", name(player), score))
+            .collect::<Vec<_>>();
+        writeln!(self.output, "Scores: {}", scores.join(", "))?;
+        writeln!(self.output, "Table:")?;
+        for player in 0..N {
+            let you = if player == view.player() {
+                " (you)"
+            } else {
+                ""
+            };
+            write!(
+                self.output,
+                "  {}. {}{}: {} in hand, {} played",
+                player + 1,
+                name(player),
+                you,
+                view.hand_counts()[player],
+                view.stack_heights()[player],
+            )?;
+            let revealed = view.revealed(player);
+            if !revealed.is_empty() {
+                let revealed = revealed
+                    .iter()
+                    .map(|(index, card)| format!("#{} {}", index + 1, card))
+                    .collect::<Vec<_>>();
+                write!(self.output, ", flipped {}", revealed.join(", "))?;
+            }
+            writeln!(self.output)?;
+        }
+        writeln!(self.output, "Your hand: {}", view.hand())?;
+        let played = view
+            .cards_played()
+            .iter()
+            .map(Card::to_string)
+            .collect::<Vec<_>>();
+        writeln!(
+            self.output,
+            "Your cards played (bottom to top): {}",
+            if played.is_empty() {
+                String::from("none")
+            } else {
+                played.join(", ")
+            }
+        )?;
+        match view.state() {
+            State::Bidding {
+                highest_bid,
+                highest_bidder,
+                max_bid,
+                passed,
+                ..
+            } => {
+                let passed = (0..N)
+                    .filter(|player| passed[*player])
+                    .map(name)
+                    .collect::<Vec<_>>();
+                writeln!(
+                    self.output,
+                    "Highest bid: {} by {} (maximum {}), passed: {}",
+                    highest_bid,
+                    name(*highest_bidder),
+                    max_bid,
+                    if passed.is_empty() {
+                        String::from("no one")
+                    } else {
+                        passed.join(", ")
+                    }
+                )?;
+            }
+            State::Challenging {
+                challenger, target, ..
+            } => {
+                writeln!(
+                    self.output,
+                    "{} is trying to flip {} flowers",
+                    name(*challenger),
+                    target
+                )?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    // Asks for a response until the answer makes sense for the input
+    pub fn ask_response<const N: usize>(
+        &mut self,
+        game: &Game<N>,
+        input: InputType,
+    ) -> Result<Response> {
+        use InputType::*;
+        let message = match input {
+            PlayCard => "Play a card (flower/skull):",
+            PlayCardOrStartBid => {
+                "Play a card (flower/skull) or start a bid (number):"
+            }
+            StartBid => "Start a bid (number):",
+            BidOrPass => "Raise the bid (number) or pass:",
+            FlipCard => "Flip a card (player, then optionally card number):",
+            ChooseDiscard => {
+                "Choose a card for the challenger to lose (number):"
+            }
+        };
+        loop {
+            let answer = self.prompt(message)?;
+            match parse_response(game, input, &answer) {
+                Some(response) => return Ok(response),
+                None => self.say("Sorry, I didn't understand that")?,
+            }
+        }
+    }
+}
+
+// One terminal passed round everyone playing, hiding each player's cards
+// from the next
+pub struct HotSeat<R: BufRead, W: Write> {
+    terminal: Terminal<R, W>,
+    // What's happened since the terminal was last passed on
+    log: Vec<String>,
+    holder: Option<usize>,
+}
+
+impl<R: BufRead, W: Write> HotSeat<R, W> {
+    pub fn new(terminal: Terminal<R, W>) -> Self {
+        HotSeat {
+            terminal,
+            log: Vec::new(),
+            holder: None,
+        }
+    }
+
+    // Shows everyone what's happened since the last turn, e.g. once the game
+    // is over
+    pub fn show_log(&mut self) -> Result<()> {
+        for line in self.log.drain(..) {
+            self.terminal.say(&line)?;
+        }
+        Ok(())
+    }
+
+    // Hides the previous player's cards until the next player is ready
+    fn pass_to(&mut self, player: &Player) -> Result<()> {
+        self.terminal.clear()?;
+        self.show_log()?;
+        let message = format!(
+            "Pass the device to {} and press Enter when ready",
+            player.name()
+        );
+        self.terminal.prompt(&message)?;
+        self.terminal.clear()?;
+        self.holder = Some(player.seat());
+        Ok(())
+    }
+}
+
+impl<R: BufRead, W: Write, const N: usize> Players<N> for HotSeat<R, W> {
+    fn announce(&mut self, message: &str) -> Result<()> {
+        self.log.push(message.to_owned());
+        Ok(())
+    }
+
+    fn ask(
+        &mut self,
+        table: &Table<N>,
+        player: usize,
+        input: InputType,
+    ) -> Result<Option<Response>> {
+        if self.holder != Some(player) {
+            self.pass_to(table.player(player))?;
+        } else {
+            self.show_log()?;
+        }
+        self.terminal.show_view(table, player)?;
+        self.terminal.ask_response(table.game(), input).map(Some)
+    }
+
+    fn reject(&mut self, _player: usize, error: ResponseError) -> Result<()> {
+        self.terminal.say(&error.to_string())
+    }
+}
+
+// Turns what the player typed into the response they meant, if it makes
+// sense for the type of input asked for
+fn parse_response<const N: usize>(
+    game: &Game<N>,
+    input: InputType,
+    answer: &str,
+) -> Option<Response> {
+    use InputType::*;
+    let card = match answer {
+        "f" | "flower" => Some(Card::Flower),
+        "s" | "skull" => Some(Card::Skull),
+        _ => None,
+    };
+    let number = answer.parse::<usize>().ok();
+    match input {
+        PlayCard => card.map(Response::PlayCard),
+        PlayCardOrStartBid => card
+            .map(Response::PlayCard)
+            .or_else(|| number.map(Response::Bid)),
+        StartBid => number.map(Response::Bid),
+        BidOrPass => match answer {
+            "p" | "pass" => Some(Response::Pass),
+            _ => number.map(Response::Bid),
+        },
+        FlipCard => parse_flip(game, answer),
+        // Players count cards from 1
+        ChooseDiscard => {
+            number.and_then(|n| n.checked_sub(1)).map(Response::Discard)
+        }
+    }
+}
+
+// Accepts "<player> <card>", or just "<player>" to flip their top unflipped
+// card, with both counting from 1
+fn parse_flip<const N: usize>(
+    game: &Game<N>,
+    answer: &str,
+) -> Option<Response> {
+    let mut numbers = answer
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<usize>().ok()?.checked_sub(1));
+    let player = numbers.next()??;
+    let card = match numbers.next() {
+        Some(card) => card?,
+        None => {
+            let flipped = match game.state() {
+                State::Challenging { flipped, .. } => flipped.get(player)?,
+                _ => return None,
+            };
+            (0..game.cards_played().get(player)?.len())
+                .rev()
+                .find(|index| !flipped.contains(index))?
+        }
+    };
+    if numbers.next().is_some() {
+        return None;
+    }
+    Some(Response::Flip(player, card))
+}
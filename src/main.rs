@@ -1,9 +1,176 @@
-use game::Game;
-use std::mem;
+mod args;
+mod bot;
+mod cli;
+mod replay;
+mod serve;
+mod session;
+mod simulate;
+mod tui;
+
+use args::*;
+use cli::{HotSeat, Terminal};
+use game::*;
+use session::Result;
+use tui::Tui;
+
+use clap::Parser;
+use std::fs;
+use std::io;
+use std::process;
+
+// Runs the code with `N` set to the given number of players, so it can be
+// used as the number of players for a Game
+macro_rules! with_player_count {
+    ($count:expr, $n:ident => $body:expr) => {
+        match $count {
+            2 => {
+                const $n: usize = 2;
+                $body
+            }
+            3 => {
+                const $n: usize = 3;
+                $body
+            }
+            4 => {
+                const $n: usize = 4;
+                $body
+            }
+            5 => {
+                const $n: usize = 5;
+                $body
+            }
+            6 => {
+                const $n: usize = 6;
+                $body
+            }
+            7 => {
+                const $n: usize = 7;
+                $body
+            }
+            8 => {
+                const $n: usize = 8;
+                $body
+            }
+            9 => {
+                const $n: usize = 9;
+                $body
+            }
+            10 => {
+                const $n: usize = 10;
+                $body
+            }
+            count => Err(format!("{} players isn't supported", count).into()),
+        }
+    };
+}
 
 fn main() {
-    println!("Size of Game<3>: {} bytes", mem::size_of::<Game<3>>(),);
-    println!("Size of Game<4>: {} bytes", mem::size_of::<Game<4>>(),);
-    println!("Size of Game<5>: {} bytes", mem::size_of::<Game<5>>(),);
-    println!("Size of Game<6>: {} bytes", mem::size_of::<Game<6>>(),);
+    let args = Args::parse();
+    let result = match args.command {
+        Command::Play(args) => play(&args),
+        Command::Replay(args) => replay(&args),
+        Command::Simulate(args) => {
+            with_player_count!(args.game.player_count(), N => {
+                simulate::simulate::<N>(&args)
+            })
+        }
+        Command::Serve(args) => serve(&args),
+    };
+    if let Err(err) = result {
+        eprintln!("Error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn play(args: &PlayArgs) -> Result<()> {
+    match &args.load {
+        Some(path) => {
+            let file = fs::read(path)?;
+            with_player_count!(saved_player_count(&file)?, N => {
+                play_game(load_table::<N>(&file, args)?, args)
+            })
+        }
+        None => with_player_count!(args.game.player_count(), N => {
+            let table = session::seat_players(
+                args.game.new_game::<N>()?,
+                &args.game.names,
+                args.game.shuffle_seats,
+            )?;
+            play_game(table, args)
+        }),
+    }
+}
+
+// Games saved without their table only have the game, so players are seated
+// in the order they're named
+fn load_table<const N: usize>(
+    file: &[u8],
+    args: &PlayArgs,
+) -> Result<Table<N>> {
+    match Table::load_from(file) {
+        Err(SaveError::MissingField("seats")) => {
+            let game = Game::load_from(file)?;
+            session::seat_players(game, &args.game.names, false)
+        }
+        result => Ok(result?),
+    }
+}
+
+fn play_game<const N: usize>(table: Table<N>, args: &PlayArgs) -> Result<()> {
+    let seats = args.seats.seats(N)?;
+    if args.tui {
+        let mut tui = Tui::new();
+        session::play(table, &mut tui, &seats)?;
+        return tui.finish();
+    }
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let terminal = Terminal {
+        input: stdin.lock(),
+        output: stdout.lock(),
+    };
+    let mut hot_seat = HotSeat::new(terminal);
+    session::play(table, &mut hot_seat, &seats)?;
+    hot_seat.show_log()
+}
+
+fn replay(args: &ReplayArgs) -> Result<()> {
+    let file = fs::read(&args.path)?;
+    let player_count = match Replay::<MIN_PLAYERS>::read_from(file.as_slice()) {
+        Err(ReplayError::WrongPlayerCount { found, .. }) => found,
+        _ => MIN_PLAYERS,
+    };
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut terminal = Terminal {
+        input: stdin.lock(),
+        output: stdout.lock(),
+    };
+    with_player_count!(player_count, N => {
+        let replay = Replay::<N>::read_from(file.as_slice())?;
+        replay::show(replay, args, &mut terminal)
+    })
+}
+
+fn serve(args: &ServeArgs) -> Result<()> {
+    with_player_count!(args.game.player_count(), N => {
+        let seats = args.seats.seats(N)?;
+        let game = args.game.new_game::<N>()?;
+        let table = session::seat_players(
+            game,
+            &args.game.names,
+            args.game.shuffle_seats,
+        )?;
+        serve::serve(table, &seats, &args.address).map(|_| ())
+    })
+}
+
+// Save files can only be loaded for the right number of players, so find out
+// what that is
+fn saved_player_count(file: &[u8]) -> Result<usize> {
+    match Game::<MIN_PLAYERS>::load_from(file) {
+        Ok(_) => Ok(MIN_PLAYERS),
+        Err(SaveError::WrongPlayerCount { found, .. }) => Ok(found),
+        Err(err) => Err(err.into()),
+    }
 }
@@ -0,0 +1,66 @@
+use crate::args::ReplayArgs;
+use crate::cli::Terminal;
+use crate::session::{self, Players, Result, Seats};
+use game::*;
+
+use std::io::{BufRead, Write};
+use std::slice;
+
+// Gives the game the recorded responses, showing everyone everything
+struct Playback<'a, R: BufRead, W: Write> {
+    responses: slice::Iter<'a, Response>,
+    terminal: &'a mut Terminal<R, W>,
+    step: bool,
+}
+
+impl<R: BufRead, W: Write, const N: usize> Players<N> for Playback<'_, R, W> {
+    fn announce(&mut self, message: &str) -> Result<()> {
+        self.terminal.say(message)
+    }
+
+    fn ask(
+        &mut self,
+        _: &Table<N>,
+        _: usize,
+        _: InputType,
+    ) -> Result<Option<Response>> {
+        let response = self.responses.next().copied();
+        if response.is_some() && self.step {
+            self.terminal.prompt("Press Enter to continue")?;
+        }
+        Ok(response)
+    }
+
+    fn reject(&mut self, _: usize, error: ResponseError) -> Result<()> {
+        Err(error.into())
+    }
+
+    fn sees_everything(&self) -> bool {
+        true
+    }
+}
+
+// Shows every response and event of a recorded game, card faces and all
+pub fn show<const N: usize, R: BufRead, W: Write>(
+    mut replay: Replay<N>,
+    args: &ReplayArgs,
+    terminal: &mut Terminal<R, W>,
+) -> Result<()> {
+    replay.jump_to(0);
+    let table = session::seat_recorded_players(
+        replay.game().clone(),
+        &args.names,
+        replay.seating(),
+    )?;
+    let mut playback = Playback {
+        responses: replay.responses().iter(),
+        terminal,
+        step: args.step,
+    };
+    let table = session::play(table, &mut playback, &Seats::default())?;
+    // Replays of unfinished games run out of responses before anyone wins
+    if table.game().winner().is_none() {
+        playback.terminal.say("The replay ends here")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use crate::cli::Terminal;
+use crate::session::{self, Players, Result, Seats};
+use game::*;
+
+use std::io::BufReader;
+use std::net::{TcpListener, TcpStream};
+
+type Connection = Terminal<BufReader<TcpStream>, TcpStream>;
+
+// Everyone playing over the network, with one connection per person
+struct Server {
+    // One per seat, None for bots
+    connections: Vec<Option<Connection>>,
+}
+
+// Waits for a connection for every seat that isn't a bot, then plays the game
+pub fn serve<const N: usize>(
+    table: Table<N>,
+    seats: &Seats,
+    address: &str,
+) -> Result<Table<N>> {
+    let listener = TcpListener::bind(address)?;
+    println!("Listening on {}", listener.local_addr()?);
+    let mut connections = Vec::with_capacity(N);
+    for player in table.players() {
+        if seats.bots.contains(&player.seat()) {
+            connections.push(None);
+            continue;
+        }
+        println!("Waiting for {} to connect", player.name());
+        let (stream, address) = listener.accept()?;
+        println!("{} connected from {}", player.name(), address);
+        let mut connection = Terminal {
+            input: BufReader::new(stream.try_clone()?),
+            output: stream,
+        };
+        connection.say(&format!(
+            "Welcome! You're playing as {}, waiting for everyone else",
+            player.name()
+        ))?;
+        connections.push(Some(connection));
+    }
+    session::play(table, &mut Server { connections }, seats)
+}
+
+impl Server {
+    fn connection(&mut self, player: usize) -> &mut Connection {
+        self.connections[player]
+            .as_mut()
+            .expect("Bots shouldn't be asked for responses")
+    }
+}
+
+impl<const N: usize> Players<N> for Server {
+    fn announce(&mut self, message: &str) -> Result<()> {
+        println!("{}", message);
+        for connection in self.connections.iter_mut().flatten() {
+            connection.say(message)?;
+        }
+        Ok(())
+    }
+
+    fn ask(
+        &mut self,
+        table: &Table<N>,
+        player: usize,
+        input: InputType,
+    ) -> Result<Option<Response>> {
+        let connection = self.connection(player);
+        connection.show_view(table, player)?;
+        connection.ask_response(table.game(), input).map(Some)
+    }
+
+    fn reject(&mut self, player: usize, error: ResponseError) -> Result<()> {
+        self.connection(player).say(&error.to_string())
+    }
+}
@@ -0,0 +1,227 @@
+use crate::bot::Bot;
+use game::*;
+
+use std::error::Error;
+use std::fs::File;
+use std::path::PathBuf;
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+// How the people playing a game are told what's happening and asked for
+// their responses
+pub trait Players<const N: usize> {
+    // Tells everyone something that happened
+    fn announce(&mut self, message: &str) -> Result<()>;
+
+    // Asks a player for a response, showing them what they can see of the
+    // game, or None if there are no more responses to give, which stops the
+    // game where it is
+    fn ask(
+        &mut self,
+        table: &Table<N>,
+        player: usize,
+        input: InputType,
+    ) -> Result<Option<Response>>;
+
+    // Tells a player why their response wasn't accepted
+    fn reject(&mut self, player: usize, error: ResponseError) -> Result<()>;
+
+    // Whether the cards people play can be shown to everyone
+    fn sees_everything(&self) -> bool {
+        false
+    }
+}
+
+// Seats players at the game with the names given, and a default name for
+// anyone not given one, shuffling the seats if asked to
+pub fn seat_players<const N: usize>(
+    game: Game<N>,
+    names: &[String],
+    shuffle: bool,
+) -> Result<Table<N>> {
+    let defaults = (names.len()..N).map(|seat| format!("Player {}", seat + 1));
+    let table = Table::with_names(game, names.iter().cloned().chain(defaults))?;
+    Ok(if shuffle {
+        table.shuffle_seats()?
+    } else {
+        table
+    })
+}
+
+// Seats players where a replay says they sat, naming them as seat_players()
+// would have (each player's ID is where their name was given)
+pub fn seat_recorded_players<const N: usize>(
+    game: Game<N>,
+    names: &[String],
+    seating: &[PlayerId],
+) -> Result<Table<N>> {
+    let players = seating.iter().map(|id| {
+        let index = id.0 as usize;
+        let name = names
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| format!("Player {}", index + 1));
+        (*id, name)
+    });
+    Ok(Table::new(game, players)?)
+}
+
+// Everything about a game's seats other than who's sitting in them
+#[derive(Debug, Clone, Default)]
+pub struct Seats {
+    // Indexes of the seats played by bots
+    pub bots: Vec<usize>,
+    // Where to save the game after every response
+    pub save: Option<PathBuf>,
+    // Where to write a replay of the game once it's over
+    pub record: Option<PathBuf>,
+}
+
+// Plays the game until someone wins, or until the players stop giving
+// responses, returning the game as it was left
+pub fn play<const N: usize, P: Players<N>>(
+    mut table: Table<N>,
+    players: &mut P,
+    seats: &Seats,
+) -> Result<Table<N>> {
+    let seed = table.game().seed();
+    let mut bots = (0..N)
+        .map(|player| {
+            seats
+                .bots
+                .contains(&player)
+                .then(|| Bot::new(seed.wrapping_add(player as u64)))
+        })
+        .collect::<Vec<_>>();
+    loop {
+        match table.what_next()? {
+            Event::Input { player, input } => {
+                loop {
+                    let response = match &mut bots[player] {
+                        Some(bot) => bot.choose(table.game()),
+                        None => match players.ask(&table, player, input)? {
+                            Some(response) => response,
+                            None => return Ok(table),
+                        },
+                    };
+                    let message = describe_response(
+                        &table,
+                        player,
+                        response,
+                        players.sees_everything(),
+                    );
+                    match table.respond(response) {
+                        Ok(()) => {
+                            players.announce(&message)?;
+                            break;
+                        }
+                        Err(err) => players.reject(player, err)?,
+                    }
+                }
+                if let Some(path) = &seats.save {
+                    table.save_to(File::create(path)?)?;
+                }
+            }
+            event => {
+                players.announce(&table.named(event).to_string())?;
+                if let Some(message) = describe_own_flips(&table, event) {
+                    players.announce(&message)?;
+                }
+                if table.game().winner().is_some() {
+                    break;
+                }
+            }
+        }
+    }
+    let scores = table
+        .players()
+        .iter()
+        .map(|player| {
+            let score = table.game().scores()[player.seat()];
+            format!("{}: {}", player.name(), score)
+        })
+        .collect::<Vec<_>>();
+    players.announce(&format!("Final scores: {}", scores.join(", ")))?;
+    if let Some(path) = &seats.record {
+        match Replay::from_table(&table) {
+            Some(replay) => replay.write_to(File::create(path)?)?,
+            None => {
+                players.announce("This game can't be recorded as a replay")?
+            }
+        }
+    }
+    Ok(table)
+}
+
+// What a player did, for everyone to see, so doesn't give away which card
+// was played unless asked to
+fn describe_response<const N: usize>(
+    table: &Table<N>,
+    player: usize,
+    response: Response,
+    reveal: bool,
+) -> String {
+    let name = table.player(player).name();
+    match response {
+        Response::PlayCard(card) if reveal => {
+            format!("{} played a {}", name, card)
+        }
+        Response::PlayCard(_) => format!("{} played a card", name),
+        Response::Bid(n) => format!("{} bid {}", name, n),
+        Response::Pass => format!("{} passed", name),
+        Response::Flip(owner, card) => {
+            match table
+                .game()
+                .cards_played()
+                .get(owner)
+                .and_then(|cards| cards.get(card))
+            {
+                Some(flipped) => format!(
+                    "{} flipped {}'s card #{}: {}",
+                    name,
+                    table.player(owner).name(),
+                    card + 1,
+                    flipped
+                ),
+                None => {
+                    format!("{} tried to flip a card that isn't there", name)
+                }
+            }
+        }
+        Response::Discard(_) => {
+            format!("{} chose a card for the challenger to lose", name)
+        }
+    }
+}
+
+// The challenger's own cards are flipped for them as the challenge starts,
+// so no response says what they were
+fn describe_own_flips<const N: usize>(
+    table: &Table<N>,
+    event: Event,
+) -> Option<String> {
+    let game = table.game();
+    match (event, game.state()) {
+        (
+            Event::ChallengeStarted,
+            State::Challenging {
+                challenger,
+                flipped,
+                ..
+            },
+        ) if !flipped[*challenger].is_empty() => {
+            let cards = flipped[*challenger]
+                .iter()
+                .map(|index| {
+                    game.cards_played()[*challenger][*index].to_string()
+                })
+                .collect::<Vec<_>>();
+            Some(format!(
+                "{} flipped their own cards: {}",
+                table.player(*challenger).name(),
+                cards.join(", ")
+            ))
+        }
+        _ => None,
+    }
+}
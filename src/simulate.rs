@@ -0,0 +1,80 @@
+use crate::args::SimulateArgs;
+use crate::session::{self, Players, Result, Seats};
+use game::*;
+
+// Nobody's watching, the bots play amongst themselves
+struct Bots;
+
+impl<const N: usize> Players<N> for Bots {
+    fn announce(&mut self, _message: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn ask(
+        &mut self,
+        _: &Table<N>,
+        _: usize,
+        _: InputType,
+    ) -> Result<Option<Response>> {
+        unreachable!("Every seat should be a bot")
+    }
+
+    fn reject(&mut self, player: usize, error: ResponseError) -> Result<()> {
+        Err(
+            format!("Bot {} gave an invalid response: {}", player, error)
+                .into(),
+        )
+    }
+}
+
+// Plays bots against each other, then reports the results
+pub fn simulate<const N: usize>(args: &SimulateArgs) -> Result<()> {
+    let seats = Seats {
+        bots: (0..N).collect(),
+        ..Seats::default()
+    };
+    let mut wins = [0; N];
+    let mut rounds = 0;
+    // Wins are counted by player ID, as seats can be shuffled between games,
+    // and unshuffled each player's ID is their seat
+    let players = session::seat_players(
+        args.game.new_game::<N>()?,
+        &args.game.names,
+        false,
+    )?
+    .players()
+    .to_vec();
+    for index in 0..args.games {
+        let mut game = args.game.new_game::<N>()?;
+        if let Some(seed) = args.game.seed {
+            // Each game is different, but the whole run is reproducible
+            game.reseed(seed.wrapping_add(index as u64));
+        }
+        let table = session::seat_players(
+            game,
+            &args.game.names,
+            args.game.shuffle_seats,
+        )?;
+        let table = session::play(table, &mut Bots, &seats)?;
+        let game = table.game();
+        let winner = table.player(game.winner().expect("Game should be over"));
+        wins[winner.id().0 as usize] += 1;
+        rounds += game.round() + 1;
+    }
+
+    println!("Played {} games with {} players", args.games, N);
+    println!("Wins by player, whichever seat they were in:");
+    for (player, wins) in players.iter().zip(wins.iter()) {
+        println!(
+            "  {}: {} wins ({:.1}%)",
+            player.name(),
+            wins,
+            100.0 * *wins as f64 / args.games.max(1) as f64
+        );
+    }
+    println!(
+        "Average rounds per game: {:.1}",
+        rounds as f64 / args.games.max(1) as f64
+    );
+    Ok(())
+}
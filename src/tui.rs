@@ -0,0 +1,668 @@
+use crate::session::{Players, Result};
+use game::*;
+
+use ratatui::crossterm::event::{self, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{self, Block, Gauge, Paragraph, Row};
+use ratatui::{DefaultTerminal, Frame};
+
+// How many lines Page Up/Down scroll the log by
+const SCROLL_STEP: usize = 5;
+
+const HELP: &str = "←/→ choose · ↑/↓ change · Enter confirm · \
+                    PgUp/PgDn scroll log · Esc quit";
+
+// A full-screen terminal passed round everyone playing, like a HotSeat
+pub struct Tui {
+    terminal: DefaultTerminal,
+    screen: Screen,
+    holder: Option<usize>,
+}
+
+// Everything drawn on screen other than the game itself
+struct Screen {
+    log: Vec<String>,
+    // How many lines the log has been scrolled back by
+    scroll: usize,
+    // Why the last response wasn't accepted
+    status: Option<String>,
+}
+
+impl Tui {
+    // Switches the terminal to full screen until the Tui is dropped
+    pub fn new() -> Self {
+        Tui {
+            terminal: ratatui::init(),
+            screen: Screen {
+                log: Vec::new(),
+                scroll: 0,
+                status: None,
+            },
+            holder: None,
+        }
+    }
+
+    // Shows how the game ended until Enter or Esc is pressed
+    pub fn finish(&mut self) -> Result<()> {
+        loop {
+            let screen = &self.screen;
+            self.terminal.draw(|frame| {
+                screen.draw_message(frame, "Game over, press Enter to exit")
+            })?;
+            if let KeyCode::Enter | KeyCode::Esc = self.next_key()? {
+                return Ok(());
+            }
+        }
+    }
+
+    // Hides the previous player's cards until the next player is ready
+    fn pass_to(&mut self, player: &Player) -> Result<()> {
+        self.holder = None;
+        let message = format!(
+            "Pass the device to {} and press Enter when ready",
+            player.name()
+        );
+        self.wait_for_enter(&message)?;
+        self.holder = Some(player.seat());
+        Ok(())
+    }
+
+    // Shows the log and a message, failing if the player quits instead
+    fn wait_for_enter(&mut self, message: &str) -> Result<()> {
+        loop {
+            let screen = &self.screen;
+            self.terminal
+                .draw(|frame| screen.draw_message(frame, message))?;
+            match self.next_key()? {
+                KeyCode::Enter => return Ok(()),
+                KeyCode::Esc => return Err("Game abandoned".into()),
+                _ => {}
+            }
+        }
+    }
+
+    // Waits for a key press, scrolling the log if that's what it was for
+    //
+    // Ctrl-C is treated as Esc, as raw mode stops it interrupting
+    fn next_key(&mut self) -> Result<KeyCode> {
+        let key = match event::read()? {
+            event::Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            // Anything else (e.g. resizing) just needs a redraw
+            _ => return Ok(KeyCode::Null),
+        };
+        let screen = &mut self.screen;
+        match key.code {
+            KeyCode::Char('c')
+                if key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                return Ok(KeyCode::Esc)
+            }
+            KeyCode::PageUp => {
+                screen.scroll = (screen.scroll + SCROLL_STEP)
+                    .min(screen.log.len().saturating_sub(1));
+            }
+            KeyCode::PageDown => {
+                screen.scroll = screen.scroll.saturating_sub(SCROLL_STEP);
+            }
+            _ => {}
+        }
+        Ok(key.code)
+    }
+}
+
+impl Drop for Tui {
+    fn drop(&mut self) {
+        ratatui::restore();
+    }
+}
+
+impl<const N: usize> Players<N> for Tui {
+    fn announce(&mut self, message: &str) -> Result<()> {
+        self.screen.log.push(message.to_owned());
+        self.screen.scroll = 0;
+        Ok(())
+    }
+
+    fn ask(
+        &mut self,
+        table: &Table<N>,
+        player: usize,
+        input: InputType,
+    ) -> Result<Option<Response>> {
+        if self.holder != Some(player) {
+            self.pass_to(table.player(player))?;
+        }
+        let mut picker = Picker::new(table.game());
+        if picker.choices.is_empty() {
+            return Err("There's no response the game would accept".into());
+        }
+        loop {
+            let screen = &self.screen;
+            self.terminal.draw(|frame| {
+                screen.draw_game(frame, table, player, input, &picker)
+            })?;
+            match self.next_key()? {
+                KeyCode::Left => picker.move_across(false),
+                KeyCode::Right => picker.move_across(true),
+                KeyCode::Up => picker.move_within(true),
+                KeyCode::Down => picker.move_within(false),
+                KeyCode::Enter => {
+                    self.screen.status = None;
+                    return Ok(Some(picker.current()));
+                }
+                KeyCode::Esc => return Err("Game abandoned".into()),
+                _ => {}
+            }
+        }
+    }
+
+    fn reject(&mut self, _player: usize, error: ResponseError) -> Result<()> {
+        self.screen.status = Some(error.to_string());
+        Ok(())
+    }
+}
+
+// The legal responses, laid out in columns to move between with the arrow
+// keys, e.g. a column per player when flipping, with their cards as the rows
+struct Picker {
+    choices: Vec<Response>,
+    selected: usize,
+}
+
+impl Picker {
+    fn new<const N: usize>(game: &Game<N>) -> Self {
+        let mut choices = game.legal_responses();
+        choices.sort_by_key(|response| (column(*response), row(*response)));
+        Picker {
+            choices,
+            selected: 0,
+        }
+    }
+
+    fn current(&self) -> Response {
+        self.choices[self.selected]
+    }
+
+    // Moves to the first choice in the next column to the left or right
+    fn move_across(&mut self, right: bool) {
+        let current = column(self.current());
+        let next = if right {
+            self.choices
+                .iter()
+                .position(|response| column(*response) > current)
+        } else {
+            self.choices
+                .iter()
+                .rposition(|response| column(*response) < current)
+                .map(|last| {
+                    let previous = column(self.choices[last]);
+                    self.choices
+                        .iter()
+                        .position(|response| column(*response) == previous)
+                        .unwrap_or(last)
+                })
+        };
+        if let Some(next) = next {
+            self.selected = next;
+        }
+    }
+
+    // Moves up or down the current column, e.g. raising a bid or moving up a
+    // player's stack
+    fn move_within(&mut self, up: bool) {
+        let next = if up {
+            self.selected + 1
+        } else {
+            match self.selected.checked_sub(1) {
+                Some(next) => next,
+                None => return,
+            }
+        };
+        match self.choices.get(next) {
+            Some(response) if column(*response) == column(self.current()) => {
+                self.selected = next
+            }
+            _ => {}
+        }
+    }
+
+    // The choice shown for each column, which is the current one in its own
+    // column, along with whether it's the current one
+    fn columns(&self) -> Vec<(Response, bool)> {
+        let current = column(self.current());
+        let mut columns: Vec<(Response, bool)> = Vec::new();
+        for response in self.choices.iter().copied() {
+            match columns.last() {
+                Some((last, _)) if column(*last) == column(response) => {}
+                _ => columns.push((response, false)),
+            }
+        }
+        for choice in columns.iter_mut() {
+            if column(choice.0) == current {
+                *choice = (self.current(), true);
+            }
+        }
+        columns
+    }
+}
+
+// Which column of the picker a response goes in
+fn column(response: Response) -> usize {
+    match response {
+        Response::PlayCard(card) => Card::ALL
+            .iter()
+            .position(|kind| *kind == card)
+            .unwrap_or_default(),
+        Response::Bid(_) => Card::KINDS,
+        Response::Pass => Card::KINDS + 1,
+        Response::Flip(player, _) => player,
+        Response::Discard(index) => index,
+    }
+}
+
+// Which row of its column a response goes in
+fn row(response: Response) -> usize {
+    match response {
+        Response::Bid(n) => n,
+        Response::Flip(_, card) => card,
+        _ => 0,
+    }
+}
+
+impl Screen {
+    fn draw_message(&self, frame: &mut Frame, message: &str) {
+        let [main, help] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)])
+                .areas(frame.area());
+        let [message_area, log] = Layout::horizontal([
+            Constraint::Percentage(60),
+            Constraint::Percentage(40),
+        ])
+        .areas(main);
+        frame.render_widget(
+            Paragraph::new(message)
+                .bold()
+                .centered()
+                .block(Block::bordered().title("Skull")),
+            message_area,
+        );
+        self.draw_log(frame, log);
+        frame.render_widget(Line::from(HELP).dim(), help);
+    }
+
+    fn draw_game<const N: usize>(
+        &self,
+        frame: &mut Frame,
+        table: &Table<N>,
+        player: usize,
+        input: InputType,
+        picker: &Picker,
+    ) {
+        let game = table.game();
+        let view = game.view_for(player);
+        let [title, main, controls, help] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(3),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+        let [board, log] = Layout::horizontal([
+            Constraint::Percentage(60),
+            Constraint::Percentage(40),
+        ])
+        .areas(main);
+        let [seats, state, cards] = Layout::vertical([
+            Constraint::Length(N as u16 + 3),
+            Constraint::Min(4),
+            Constraint::Length(4),
+        ])
+        .areas(board);
+
+        frame.render_widget(
+            Line::from(format!(
+                "Skull: round {}, {}'s turn",
+                game.round() + 1,
+                table.player(player).name()
+            ))
+            .bold(),
+            title,
+        );
+        let target = match picker.current() {
+            Response::Flip(player, card) => Some((player, card)),
+            _ => None,
+        };
+        draw_seats(frame, seats, table, &view, target);
+        draw_state(frame, state, table, &view);
+        draw_cards(frame, cards, &view);
+        self.draw_log(frame, log);
+        draw_picker(frame, controls, table, input, picker);
+        let help_line = match &self.status {
+            Some(status) => Line::from(status.as_str()).red(),
+            None => Line::from(HELP).dim(),
+        };
+        frame.render_widget(help_line, help);
+    }
+
+    // The most recent announcements, unless scrolled back
+    fn draw_log(&self, frame: &mut Frame, area: Rect) {
+        let height = area.height.saturating_sub(2) as usize;
+        let end = self.log.len().saturating_sub(self.scroll);
+        let start = end.saturating_sub(height);
+        let lines = self.log[start..end]
+            .iter()
+            .map(|line| Line::from(line.as_str()))
+            .collect::<Vec<_>>();
+        let title = if self.scroll > 0 {
+            format!("Log ({} newer)", self.scroll)
+        } else {
+            String::from("Log")
+        };
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(title)),
+            area,
+        );
+    }
+}
+
+// Every seat's score, cards and what they're doing
+fn draw_seats<const N: usize>(
+    frame: &mut Frame,
+    area: Rect,
+    table: &Table<N>,
+    view: &PlayerView<N>,
+    target: Option<(usize, usize)>,
+) {
+    let rows = (0..N).map(|seat| {
+        let name = if seat == view.player() {
+            format!("{} (you)", table.player(seat).name())
+        } else {
+            table.player(seat).name().to_owned()
+        };
+        Row::new(vec![
+            Line::from((seat + 1).to_string()),
+            Line::from(name),
+            Line::from(view.scores()[seat].to_string()),
+            Line::from(view.hand_counts()[seat].to_string()),
+            stack(view, seat, target),
+            Line::from(seat_status(view, seat)),
+        ])
+    });
+    let widths = [
+        Constraint::Length(2),
+        Constraint::Fill(1),
+        Constraint::Length(5),
+        Constraint::Length(4),
+        Constraint::Length(2 * MAX_HAND_SIZE as u16),
+        Constraint::Length(14),
+    ];
+    let seats = widgets::Table::new(rows, widths)
+        .header(
+            Row::new(["#", "Player", "Score", "Hand", "Stack", "Status"])
+                .bold(),
+        )
+        .block(Block::bordered().title("Table"));
+    frame.render_widget(seats, area);
+}
+
+// The bidding so far or how the challenge is going
+fn draw_state<const N: usize>(
+    frame: &mut Frame,
+    area: Rect,
+    table: &Table<N>,
+    view: &PlayerView<N>,
+) {
+    let name = |seat| table.player(seat).name();
+    let block = Block::bordered().title("State");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let lines = match view.state() {
+        State::Playing { current_player } => vec![
+            format!("{} is putting down a card", name(*current_player)),
+            format!(
+                "{} cards on the table",
+                view.stack_heights().iter().sum::<usize>()
+            ),
+        ],
+        State::Bidding {
+            current_bidder,
+            highest_bid,
+            highest_bidder,
+            max_bid,
+            passed,
+        } => {
+            let passed = (0..N)
+                .filter(|player| passed[*player])
+                .map(name)
+                .collect::<Vec<_>>();
+            vec![
+                format!(
+                    "Highest bid: {} by {} (maximum {})",
+                    highest_bid,
+                    name(*highest_bidder),
+                    max_bid
+                ),
+                format!("Waiting on {}", name(*current_bidder)),
+                format!(
+                    "Passed: {}",
+                    if passed.is_empty() {
+                        String::from("no one")
+                    } else {
+                        passed.join(", ")
+                    }
+                ),
+            ]
+        }
+        State::Challenging {
+            challenger, target, ..
+        } => {
+            // A flipped skull stays face up until the challenge ends
+            let flowers = (0..N)
+                .flat_map(|seat| view.revealed(seat))
+                .filter(|(_, card)| *card == Card::Flower)
+                .count();
+            let [text, gauge] = Layout::vertical([
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .areas(inner);
+            frame.render_widget(
+                Line::from(format!(
+                    "{} is trying to flip {} flowers",
+                    name(*challenger),
+                    target
+                )),
+                text,
+            );
+            frame.render_widget(
+                Gauge::default()
+                    .ratio(progress(flowers, *target))
+                    .label(format!("{}/{} flowers", flowers, target))
+                    .green(),
+                gauge,
+            );
+            return;
+        }
+        State::Discarding {
+            challenger,
+            skull_player,
+        } => vec![format!(
+            "{} is choosing a card for {} to lose",
+            name(*skull_player),
+            name(*challenger)
+        )],
+        State::Finished { winner, .. } => {
+            vec![format!("{} has won!", name(*winner))]
+        }
+    };
+    let lines = lines.into_iter().map(Line::from).collect::<Vec<_>>();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_picker<const N: usize>(
+    frame: &mut Frame,
+    area: Rect,
+    table: &Table<N>,
+    input: InputType,
+    picker: &Picker,
+) {
+    use InputType::*;
+    let title = match input {
+        PlayCard => "Play a card",
+        PlayCardOrStartBid => "Play a card or start a bid",
+        StartBid => "Start a bid",
+        BidOrPass => "Raise the bid or pass",
+        FlipCard => "Flip a card",
+        ChooseDiscard => "Choose a card for the challenger to lose",
+    };
+    let mut spans = Vec::new();
+    for (response, current) in picker.columns() {
+        let label = format!(" {} ", label(table, response));
+        spans.push(if current {
+            Span::styled(label, Style::new().reversed().bold())
+        } else {
+            Span::raw(label)
+        });
+        spans.push(Span::raw(" "));
+    }
+    frame.render_widget(
+        Paragraph::new(Line::from(spans)).block(Block::bordered().title(title)),
+        area,
+    );
+}
+
+fn label<const N: usize>(table: &Table<N>, response: Response) -> String {
+    match response {
+        Response::PlayCard(card) => format!("Play {}", card),
+        Response::Bid(n) => format!("Bid {}", n),
+        Response::Pass => String::from("Pass"),
+        Response::Flip(player, card) => {
+            format!("{}'s card #{}", table.player(player).name(), card + 1)
+        }
+        Response::Discard(index) => format!("Card #{}", index + 1),
+    }
+}
+
+// The viewing player's own cards
+fn draw_cards<const N: usize>(
+    frame: &mut Frame,
+    area: Rect,
+    view: &PlayerView<N>,
+) {
+    let in_hand = (*view.hand() - view.cards_played()).unwrap_or_default();
+    let lines = vec![
+        Line::from(format!("In hand: {}", card_list(&in_hand.as_vec()))),
+        Line::from(format!(
+            "Played (bottom to top): {}",
+            card_list(view.cards_played())
+        )),
+    ];
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::bordered().title("Your cards")),
+        area,
+    );
+}
+
+fn card_list(cards: &[Card]) -> String {
+    if cards.is_empty() {
+        return String::from("none");
+    }
+    cards
+        .iter()
+        .map(Card::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// A player's stack from the bottom up, with flipped cards face up, the
+// viewing player's own cards in lowercase and the flip target highlighted
+fn stack<const N: usize>(
+    view: &PlayerView<N>,
+    seat: usize,
+    target: Option<(usize, usize)>,
+) -> Line<'static> {
+    let spans = (0..view.stack_heights()[seat])
+        .flat_map(|index| {
+            let revealed = view
+                .revealed(seat)
+                .iter()
+                .find(|(flipped, _)| *flipped == index)
+                .map(|(_, card)| *card);
+            let (symbol, style) = match revealed {
+                Some(card) => (
+                    symbol(card).to_owned(),
+                    Style::new().bold().fg(colour(card)),
+                ),
+                None if seat == view.player() => (
+                    symbol(view.cards_played()[index]).to_lowercase(),
+                    Style::new().dim(),
+                ),
+                None => (String::from("▮"), Style::new()),
+            };
+            let style = if target == Some((seat, index)) {
+                style.reversed()
+            } else {
+                style
+            };
+            vec![Span::styled(symbol, style), Span::raw(" ")]
+        })
+        .collect::<Vec<_>>();
+    Line::from(spans)
+}
+
+fn symbol(card: Card) -> &'static str {
+    match card {
+        Card::Flower => "F",
+        Card::Skull => "S",
+        _ => "?",
+    }
+}
+
+fn colour(card: Card) -> Color {
+    match card {
+        Card::Flower => Color::Green,
+        Card::Skull => Color::Red,
+        _ => Color::Reset,
+    }
+}
+
+fn seat_status<const N: usize>(view: &PlayerView<N>, seat: usize) -> String {
+    let status = match view.state() {
+        _ if view.hand_counts()[seat] == 0 => "out",
+        State::Playing { current_player } if *current_player == seat => {
+            "playing"
+        }
+        State::Bidding { passed, .. } if passed[seat] => "passed",
+        State::Bidding {
+            highest_bid,
+            highest_bidder,
+            ..
+        } if *highest_bidder == seat => {
+            return format!("bid {}", highest_bid);
+        }
+        State::Bidding { current_bidder, .. } if *current_bidder == seat => {
+            "bidding"
+        }
+        State::Challenging { challenger, .. } if *challenger == seat => {
+            "challenging"
+        }
+        State::Discarding { challenger, .. } if *challenger == seat => {
+            "losing a card"
+        }
+        State::Discarding { skull_player, .. } if *skull_player == seat => {
+            "choosing"
+        }
+        State::Finished { winner, .. } if *winner == seat => "winner",
+        _ => "",
+    };
+    status.to_owned()
+}
+
+fn progress(flowers: usize, target: usize) -> f64 {
+    if target == 0 {
+        return 1.0;
+    }
+    (flowers as f64 / target as f64).min(1.0)
+}